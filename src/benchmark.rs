@@ -1,38 +1,111 @@
 // Benchmarking system for my Advent of Code solutions.
-#[allow(unused_imports)]
+// Every registered day gets its own group, e.g. `2023/day05`, measuring parse, part1, part2 and
+// the whole day end to end. Parts that are not implemented yet are left out, and so are days
+// without any implemented part. Only some days or steps can be benchmarked by setting
+// MISTLETOE_BENCH_DAYS to a selection such as "2023:1-5 12", and MISTLETOE_BENCH_STEPS to
+// a list such as "parse,part2". Criterion's own filter works too: `cargo bench -- 2023/day05/part1`.
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mistletoe::runner::select::Target;
+use mistletoe::runner::solution::{Parsed, Runnable};
+use mistletoe::runner::{self, Source};
+use mistletoe::solutions;
 
-// Load the benchmark functions from each day.
-mod solutions;
-mod library;
-
-criterion_group!{
-    benches,
-	solutions::day01::benchmark,
-	solutions::day02::benchmark,
-	solutions::day03::benchmark,
-	solutions::day04::benchmark,
-	solutions::day05::benchmark,
-	solutions::day06::benchmark,
-	solutions::day07::benchmark,
-	solutions::day08::benchmark,
-	solutions::day09::benchmark,
-	solutions::day10::benchmark,
-	solutions::day11::benchmark,
-	solutions::day12::benchmark,
-	solutions::day13::benchmark,
-	solutions::day14::benchmark,
-	solutions::day15::benchmark,
-	solutions::day16::benchmark,
-	solutions::day17::benchmark,
-	solutions::day18::benchmark,
-	solutions::day19::benchmark,
-	solutions::day20::benchmark,
-	solutions::day21::benchmark,
-	solutions::day22::benchmark,
-	solutions::day23::benchmark,
-	solutions::day24::benchmark,
-	solutions::day25::benchmark
+const DAYS_VAR: &str = "MISTLETOE_BENCH_DAYS";
+const STEPS_VAR: &str = "MISTLETOE_BENCH_STEPS";
+const STEPS: [&str; 4] = ["parse", "part1", "part2", "total"];
+
+// Read the days and steps to benchmark from the environment, everything when they are not set.
+fn filters() -> (Vec<Target>, Vec<String>) {
+    let targets = match std::env::var(DAYS_VAR) {
+        Ok(days) => days
+            .split_whitespace()
+            .map(|t| {
+                t.parse()
+                    .unwrap_or_else(|e| panic!("Invalid {DAYS_VAR}: {e}"))
+            })
+            .collect(),
+        Err(_) => vec![Target::all()],
+    };
+    let steps: Vec<String> = match std::env::var(STEPS_VAR) {
+        Ok(steps) => steps.split(',').map(|s| s.trim().to_string()).collect(),
+        Err(_) => STEPS.iter().map(|s| s.to_string()).collect(),
+    };
+    if let Some(step) = steps.iter().find(|s| !STEPS.contains(&s.as_str())) {
+        panic!("Invalid {STEPS_VAR}: unknown step '{step}', expected one of {STEPS:?}");
+    }
+    (targets, steps)
+}
+
+// Check whether a part returns an answer for the real input, unimplemented and failing parts are not benchmarked.
+fn implemented(solution: &dyn Runnable, input: &Parsed, part: u8) -> bool {
+    let answer = match part {
+        1 => solution.part1(input),
+        _ => solution.part2(input),
+    };
+    answer.is_ok_and(|answer| answer.is_implemented())
+}
+
+// Benchmark every selected solution that has a real input and at least one implemented part.
+fn benchmark(c: &mut Criterion) {
+    let (targets, steps) = filters();
+    let selected = solutions::filter(|s| targets.iter().any(|t| t.matches(s.year(), s.day())));
+
+    for solution in selected {
+        let source = Source::data();
+        let path = source.describe(solution);
+        let label = runner::label(solution);
+        let data = match source.read(solution) {
+            Ok(data) => data,
+            Err(e) => {
+                eprintln!("Skipping {label}, could not read {path}: {e}");
+                continue;
+            }
+        };
+        let input = match solution.parse(&data) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping {label}, could not parse {path}: {e}");
+                continue;
+            }
+        };
+
+        let parts: Vec<u8> = [1, 2]
+            .into_iter()
+            .filter(|&part| implemented(solution, &input, part))
+            .collect();
+        if parts.is_empty() {
+            eprintln!("Skipping {label}, no part is implemented yet");
+            continue;
+        }
+
+        let runs = |step: &str| steps.iter().any(|s| s == step);
+        let mut group = c.benchmark_group(format!("{}/day{:02}", solution.year(), solution.day()));
+        if runs("parse") {
+            group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(&data))));
+        }
+        if parts.contains(&1) && runs("part1") {
+            group.bench_function("part1", |b| b.iter(|| solution.part1(black_box(&input))));
+        }
+        if parts.contains(&2) && runs("part2") {
+            group.bench_function("part2", |b| b.iter(|| solution.part2(black_box(&input))));
+        }
+        if runs("total") {
+            group.bench_function("total", |b| {
+                b.iter(|| {
+                    let input = solution.parse(black_box(&data))?;
+                    parts
+                        .iter()
+                        .map(|&part| match part {
+                            1 => solution.part1(&input),
+                            _ => solution.part2(&input),
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+            });
+        }
+        group.finish();
+    }
 }
 
-criterion_main!(benches);
\ No newline at end of file
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
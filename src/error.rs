@@ -0,0 +1,127 @@
+// The error type shared by the library, the runner and the solutions.
+// Lines and columns count from 1, so they can be matched against an editor directly.
+use std::time::Duration;
+use std::{fmt, io};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    // The input could not be read at all.
+    Io(io::Error),
+    // The input does not have the shape the puzzle describes.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    // A grid contains a character that has no meaning for the puzzle.
+    InvalidGridChar {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    // The input is well formed, but there is no answer for it.
+    Unsolvable(String),
+    // The settings are missing something, such as the session token.
+    Config(String),
+    // A request to the puzzle website failed, or its response could not be understood.
+    Http(String),
+    // The day used up its time budget before it finished.
+    TimedOut(Duration),
+    // The solution panicked, with the message it panicked with.
+    Panicked(String),
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Error::Unsolvable(message.into())
+    }
+
+    pub fn http(message: impl Into<String>) -> Self {
+        Error::Http(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{e}"),
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {line}, column {column}: {message}"),
+            Error::InvalidGridChar {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "line {line}, column {column}: invalid grid character {found:?}"
+            ),
+            Error::Unsolvable(message) => write!(f, "no solution: {message}"),
+            Error::Config(message) => write!(f, "configuration: {message}"),
+            Error::Http(message) => write!(f, "request failed: {message}"),
+            Error::TimedOut(budget) => write!(f, "timed out after {budget:?}"),
+            Error::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Error::parse(3, 7, "expected a number").to_string(),
+            "line 3, column 7: expected a number"
+        );
+        let e = Error::InvalidGridChar {
+            line: 1,
+            column: 2,
+            found: '?',
+        };
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 2: invalid grid character '?'"
+        );
+        assert_eq!(
+            Error::unsolvable("no loop").to_string(),
+            "no solution: no loop"
+        );
+        assert_eq!(
+            Error::TimedOut(Duration::from_millis(1500)).to_string(),
+            "timed out after 1.5s"
+        );
+        assert_eq!(
+            Error::Panicked("index out of bounds".to_string()).to_string(),
+            "panicked: index out of bounds"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+// Test harness for the examples of every registered solution.
+// Every case in `testdata/<year>/examples.json` becomes its own test for each part it checks,
+// named like `2023/day10/larger/part2`, so a single case can be run with `cargo test --test examples larger`.
+use libtest_mimic::{Arguments, Trial};
+use mistletoe::runner::examples;
+
+fn main() {
+    let args = Arguments::from_args();
+    let cases = examples::cases().expect("Could not read the example metadata");
+
+    let trials = cases
+        .into_iter()
+        .flat_map(|(solution, example)| {
+            example.parts().into_iter().map(move |part| {
+                let name = format!(
+                    "{}/day{:02}/{}/part{part}",
+                    solution.year(),
+                    solution.day(),
+                    example.name
+                );
+                let example = example.clone();
+                Trial::test(name, move || {
+                    examples::check(solution, &example, part)?;
+                    Ok(())
+                })
+            })
+        })
+        .collect();
+
+    libtest_mimic::run(&args, trials).exit();
+}
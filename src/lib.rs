@@ -0,0 +1,6 @@
+// Mistletoe is a small framework for my Advent of Code solutions.
+// The library is shared by the runner binary, the benchmark harness and the tests.
+pub mod error;
+pub mod library;
+pub mod runner;
+pub mod solutions;
@@ -1,4 +1,6 @@
 // This mod file contains all modules that are part of the library.
 // Modules are grouped by their functionality.
+pub mod cancel;
 pub mod containers;
+pub mod search;
 pub mod utility;
\ No newline at end of file
@@ -0,0 +1,69 @@
+// This module lets long running code give up once it is out of time.
+// Whoever runs it installs a token on the thread doing the work, and the work calls `check` inside its
+// long loops, which fails as soon as the time budget is used up. The token belongs to the thread it
+// was installed on, so checks on other threads, e.g. inside rayon, never fail.
+use crate::error::{Error, Result};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+// A time budget that started running when the token was made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    deadline: Instant,
+    budget: Duration,
+}
+
+impl Token {
+    pub fn new(budget: Duration) -> Token {
+        Token {
+            deadline: Instant::now() + budget,
+            budget,
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(Error::TimedOut(self.budget))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<Option<Token>> = const { Cell::new(None) };
+}
+
+// Run the given function with a token installed on the current thread.
+pub fn with<T>(token: Token, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.replace(Some(token));
+    let value = f();
+    CURRENT.set(previous);
+    value
+}
+
+// Fail once the work running on this thread is out of time, never fails without a token.
+pub fn check() -> Result<()> {
+    CURRENT.get().map_or(Ok(()), |token| token.check())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_tokens() {
+        assert!(check().is_ok());
+        let expired = Token::new(Duration::ZERO);
+        assert!(matches!(
+            with(expired, check),
+            Err(Error::TimedOut(budget)) if budget == Duration::ZERO
+        ));
+        assert!(with(Token::new(Duration::from_secs(60)), check).is_ok());
+        assert!(check().is_ok());
+    }
+}
@@ -1,4 +1,6 @@
 // Contains various custom containers, and their related functionality.
 // Also contains functions for standard containers.
+pub mod direction;
 pub mod grid;
-pub mod vector;
\ No newline at end of file
+pub mod point;
+pub mod vector;
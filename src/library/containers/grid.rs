@@ -1,82 +1,196 @@
 // This module contains a custom written grid container.
-// The container is a single-dimensional character vector, alongside a width of the size of each row.
+// The container is a single-dimensional vector of cells, alongside a width of the size of each row.
+// Cells are characters unless stated otherwise, so a plain `Grid` is still a character grid.
+use crate::error::{Error, Result};
+use crate::library::containers::direction::{Direction, Direction8};
+pub use crate::library::containers::point::{Point, Position};
+use crate::library::utility::parsing;
+use std::cmp::Ordering;
+use std::fmt::Display;
 use transpose::transpose as external_transpose;
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
-pub struct Grid {
-    grid: Vec<char>,
+pub struct Grid<T = char> {
+    grid: Vec<T>,
     width: usize,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
-}
-
 #[allow(unused)]
-impl Point {
-    pub fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
+impl Grid {
+    // Create a new grid from a vector of strings, where each string is a row.
+    pub fn from_rows(grid: Vec<String>, width: usize) -> Grid {
+        Grid::new(grid.iter().flat_map(|s| s.chars()).collect(), width)
+    }
+
+    // Create a new grid from a vector of strings, where each string is a column.
+    pub fn from_columns(grid: Vec<String>, width: usize) -> Grid {
+        let mut chars = Vec::with_capacity(grid.len() * width);
+        for i in 0..width {
+            for row in grid.iter() {
+                chars.push(row.chars().nth(i).unwrap());
+            }
+        }
+        Grid::new(chars, width)
+    }
+
+    // Parse the lines of an input into a grid, every row needs the same width and only allowed characters.
+    pub fn parse(data: &[String], allowed: &str) -> Result<Grid> {
+        Grid::parse_with(data, |c| allowed.contains(c).then_some(c))
+    }
+
+    // Draw a route onto a copy of the grid, every cell moved onto shows the arrow it was entered with.
+    // Positions after one another need to share a row or column, but they do not need to be next to
+    // each other, so searches that move several cells at once can be drawn too. The start is left as it is.
+    // This is only used to show what a solution did, so moves that are not straight are skipped, and a
+    // move leaving the grid is drawn up to the edge, rather than giving up on the whole drawing.
+    pub fn draw_path(&self, path: &[Position]) -> Grid {
+        let mut drawing = self.clone();
+        for pair in path.windows(2) {
+            let (mut pos, to) = (pair[0], pair[1]);
+            let Some(dir) = heading(pos, to) else {
+                continue;
+            };
+            while pos != to {
+                let Some(next) = self.step(pos, dir) else {
+                    break;
+                };
+                pos = next;
+                *drawing.cell_mut(pos.y, pos.x).unwrap() = dir.arrow();
+            }
+        }
+        drawing
+    }
+
+    // Mark the given cells on a copy of the grid, cells outside the grid are ignored.
+    pub fn highlight(&self, cells: impl IntoIterator<Item = Position>, mark: char) -> Grid {
+        let mut drawing = self.clone();
+        for pos in cells {
+            if let Some(cell) = drawing.cell_mut(pos.y, pos.x) {
+                *cell = mark;
+            }
+        }
+        drawing
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub struct Position {
-    pub x: usize,
-    pub y: usize,
+// The direction to head in from one position to reach another on the same row or column.
+fn heading(from: Position, to: Position) -> Option<Direction> {
+    match (to.x.cmp(&from.x), to.y.cmp(&from.y)) {
+        (Ordering::Equal, Ordering::Less) => Some(Direction::Up),
+        (Ordering::Greater, Ordering::Equal) => Some(Direction::Right),
+        (Ordering::Equal, Ordering::Greater) => Some(Direction::Down),
+        (Ordering::Less, Ordering::Equal) => Some(Direction::Left),
+        _ => None,
+    }
 }
 
 #[allow(unused)]
-impl Position {
-    pub fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
+impl Grid<u8> {
+    // Parse a grid of single digits, such as heat loss or tree heights.
+    pub fn from_digits(data: &[String]) -> Result<Grid<u8>> {
+        Grid::parse_with(data, |c| c.to_digit(10).map(|d| d as u8))
     }
 }
 
 #[allow(unused)]
-impl Grid {
-    // The base new function, it takes a vector of characters and a width.
-    pub fn new(grid: Vec<char>, width: usize) -> Grid {
+impl<T> Grid<T> {
+    // The base new function, it takes a vector of cells and a width.
+    pub fn new(grid: Vec<T>, width: usize) -> Grid<T> {
         Grid { grid, width }
     }
 
-    // Create a new grid from a vector of strings, where each string is a row.
-    pub fn from_rows(grid: Vec<String>, width: usize) -> Grid {
-        Grid::new(grid.iter().flat_map(|s| s.chars()).collect(), width)
-    }
+    // Parse the lines of an input into a grid, turning every character into a cell.
+    // Every row needs the same width, and a character without a cell is an invalid one.
+    // Widths and columns count characters, and a grid without any cells is an error.
+    pub fn parse_with(data: &[String], cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+        let width = match data.first() {
+            None => return Err(Error::parse(1, 1, "expected a grid, found no lines")),
+            Some(row) if row.is_empty() => {
+                return Err(Error::parse(1, 1, "expected a grid, found an empty row"))
+            }
+            Some(row) => row.chars().count(),
+        };
+        let mut grid = Vec::with_capacity(width * data.len());
 
-    // Create a new grid from a vector of strings, where each string is a column.
-    pub fn from_columns(grid: Vec<String>, width: usize) -> Grid {
-        let mut chars = Vec::with_capacity(grid.len() * width);
-        for i in 0..width {
-            for j in 0..grid.len() {
-                chars.push(grid[j].chars().nth(i).unwrap());
+        for line in parsing::lines(data) {
+            let cells = line
+                .text
+                .chars()
+                .enumerate()
+                .map(|(i, c)| cell(c).ok_or_else(|| line.invalid_char(i, c)))
+                .collect::<Result<Vec<T>>>()?;
+            if cells.len() != width {
+                let message = format!("expected {width} columns, found {}", cells.len());
+                return Err(Error::parse(line.number, 1, message));
             }
+            grid.extend(cells);
         }
-        Grid::new(chars, width)
+
+        Ok(Grid::new(grid, width))
+    }
+
+    // Convert every cell, keeping the shape of the grid.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.grid.iter().map(f).collect(), self.width)
     }
 
-    pub fn get(&self, row: usize, col: usize) -> Option<char> {
+    // Get a reference to a cell, for cells that are expensive to copy.
+    pub fn cell(&self, row: usize, col: usize) -> Option<&T> {
         if row >= self.height() || col >= self.width() {
             None
         } else {
-            Some(self.grid[row * self.width + col])
+            Some(&self.grid[row * self.width + col])
         }
     }
 
-    pub fn get_pos(&self, pos: Position) -> Option<char> {
-        self.get(pos.y, pos.x)
-    }
-
-    pub fn get_point(&self, point: Point) -> Option<char> {
-        self.get(point.y as usize, point.x as usize)
+    pub fn cell_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.height() || col >= self.width() {
+            None
+        } else {
+            Some(&mut self.grid[row * self.width + col])
+        }
     }
 
     pub fn is_in_bounds(&self, pos: Position) -> bool {
         pos.x < self.width() && pos.y < self.height()
     }
 
+    // Get the position of a point, None when the point lies outside the grid.
+    pub fn position(&self, point: Point) -> Option<Position> {
+        let pos = Position::try_from(point).ok()?;
+        self.is_in_bounds(pos).then_some(pos)
+    }
+
+    // Take a single step from a position, None when that steps off the grid.
+    pub fn step(&self, pos: Position, dir: impl Into<Direction8>) -> Option<Position> {
+        let delta = dir.into().delta();
+        let x = pos.x.checked_add_signed(delta.x as isize)?;
+        let y = pos.y.checked_add_signed(delta.y as isize)?;
+        let next = Position::new(x, y);
+        self.is_in_bounds(next).then_some(next)
+    }
+
+    // Get the positions above, below, left and right of a position, as far as they are on the grid.
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    // Get the positions diagonally next to a position, as far as they are on the grid.
+    pub fn diagonal_neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::DIAGONALS
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    // Get all eight positions around a position, as far as they are on the grid.
+    pub fn all_neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -85,8 +199,45 @@ impl Grid {
         self.grid.len() / self.width
     }
 
+    // Flip the grid horizontally, (on the x-axis).
+    pub fn flip_x(&mut self) {
+        let height = self.grid.len() / self.width;
+        for row in 0..height / 2 {
+            for col in 0..self.width {
+                let top_index = row * self.width + col;
+                let bottom_index = (height - 1 - row) * self.width + col;
+                self.grid.swap(top_index, bottom_index);
+            }
+        }
+    }
+
+    // Flip the grid vertically, (on the y-axis).
+    pub fn flip_y(&mut self) {
+        let height = self.grid.len() / self.width;
+        for row in 0..height {
+            let start = row * self.width;
+            let end = start + self.width;
+            self.grid[start..end].reverse();
+        }
+    }
+}
+
+#[allow(unused)]
+impl<T: Clone> Grid<T> {
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        self.cell(row, col).cloned()
+    }
+
+    pub fn get_pos(&self, pos: Position) -> Option<T> {
+        self.get(pos.y, pos.x)
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<T> {
+        self.get(point.y as usize, point.x as usize)
+    }
+
     // Get the rows of the grid, as a series of slices.
-    pub fn rows(&self) -> Vec<Vec<char>> {
+    pub fn rows(&self) -> Vec<Vec<T>> {
         self.grid
             .chunks(self.width)
             .map(|chunk| chunk.to_vec())
@@ -94,7 +245,7 @@ impl Grid {
     }
 
     // Get the columns of the grid, as a series of slices.
-    pub fn columns(&self) -> Vec<Vec<char>> {
+    pub fn columns(&self) -> Vec<Vec<T>> {
         (0..self.width)
             .map(|i| {
                 self.grid
@@ -102,32 +253,39 @@ impl Grid {
                     .skip(i)
                     .step_by(self.width)
                     .cloned()
-                    .collect::<Vec<char>>()
+                    .collect::<Vec<T>>()
             })
             .collect()
     }
+}
 
+#[allow(unused)]
+impl<T: Display> Grid<T> {
     // Function that will output the grid in a readable format as a giant string.
     pub fn to_string(&self, newlines: bool) -> String {
+        let row = |chunk: &[T]| chunk.iter().map(|c| c.to_string()).collect::<String>();
         if newlines {
             self.grid
                 .chunks(self.width)
-                .map(|chunk| chunk.iter().collect::<String>())
+                .map(row)
                 .collect::<Vec<String>>()
                 .join("\n")
         } else {
-            self.grid.iter().collect::<String>()
+            row(&self.grid)
         }
     }
+}
 
+#[allow(unused)]
+impl<T: Copy> Grid<T> {
     // Transpose the 1d grid using the transpose crate.
     pub fn transpose(&mut self) {
         // Get the width and height of the grid.
         let width = self.width;
         let height = self.grid.len() / width;
 
-        // Create a new vector of characters of same length as the grid.
-        let mut new_grid: Vec<char> = vec![' '; self.grid.len()];
+        // Create a new vector of cells of same length as the grid, its contents get overwritten.
+        let mut new_grid = self.grid.clone();
 
         // Perform the transpose.
         external_transpose(&self.grid, &mut new_grid, width, height);
@@ -138,26 +296,127 @@ impl Grid {
         // Set the width to the new height.
         self.width = height;
     }
+}
 
-    // Flip the grid horizontally, (on the x-axis).
-    pub fn flip_x(&mut self) {
-        let height = self.grid.len() / self.width;
-        for row in 0..height / 2 {
-            for col in 0..self.width {
-                let top_index = row * self.width + col;
-                let bottom_index = (height - 1 - row) * self.width + col;
-                self.grid.swap(top_index, bottom_index);
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
     }
 
-    // Flip the grid vertically, (on the y-axis).
-    pub fn flip_y(&mut self) {
-        let height = self.grid.len() / self.width;
-        for row in 0..height {
-            let start = row * self.width;
-            let end = start + self.width;
-            self.grid[start..end].reverse();
-        }
+    #[test]
+    fn char_grid() {
+        let mut grid = Grid::parse(&lines("#.O\n..#"), ".#O").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(0, 2), Some('O'));
+        assert_eq!(grid.get(2, 0), None);
+        grid.transpose();
+        assert_eq!(grid.to_string(true), "#.\n..\nO#");
+        grid.flip_x();
+        assert_eq!(
+            grid.rows(),
+            vec![vec!['O', '#'], vec!['.', '.'], vec!['#', '.']]
+        );
+
+        let e = Grid::parse(&lines("#.\n.x"), ".#").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: invalid grid character 'x'"
+        );
+        assert!(Grid::parse(&lines("#.\n."), ".#").is_err());
+
+        // Empty inputs have no grid, and rows are measured in characters rather than bytes.
+        let e = Grid::parse(&[], ".#").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 1: expected a grid, found no lines"
+        );
+        assert!(Grid::parse(&lines("\n"), ".#").is_err());
+        let grid = Grid::parse(&lines("é.\n.é"), ".é").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        let e = Grid::parse(&lines("éé\néx"), "é").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: invalid grid character 'x'"
+        );
+    }
+
+    #[test]
+    fn digit_grid() {
+        let mut grid = Grid::from_digits(&lines("123\n456")).unwrap();
+        assert_eq!(grid.get_pos(Position::new(2, 1)), Some(6));
+        assert_eq!(grid.columns()[1], vec![2, 5]);
+        grid.flip_y();
+        assert_eq!(grid.to_string(false), "321654");
+
+        let doubled = grid.map(|&d| u32::from(d) * 2);
+        assert_eq!(doubled.get(1, 0), Some(12));
+        let chars = doubled.map(|&d| if d > 6 { '#' } else { '.' });
+        assert_eq!(chars.to_string(true), "...\n###");
+        assert!(Grid::from_digits(&lines("12\n3a")).is_err());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::from_digits(&lines("123\n456\n789")).unwrap();
+        let corner = Position::new(0, 0);
+        assert_eq!(grid.step(corner, Direction::Up), None);
+        assert_eq!(
+            grid.step(corner, Direction8::DownRight),
+            Some(Position::new(1, 1))
+        );
+        assert_eq!(grid.step(Position::new(2, 1), Direction::Right), None);
+
+        let values = |positions: Vec<Position>| -> Vec<u8> {
+            positions
+                .into_iter()
+                .filter_map(|p| grid.get_pos(p))
+                .collect()
+        };
+        assert_eq!(values(grid.neighbours(corner).collect()), vec![2, 4]);
+        assert_eq!(
+            values(grid.neighbours(Position::new(1, 1)).collect()),
+            vec![2, 6, 8, 4]
+        );
+        assert_eq!(
+            values(grid.diagonal_neighbours(Position::new(1, 0)).collect()),
+            vec![6, 4]
+        );
+        assert_eq!(
+            values(grid.all_neighbours(Position::new(2, 2)).collect()),
+            vec![6, 8, 5]
+        );
+
+        assert_eq!(grid.position(Point::new(2, 1)), Some(Position::new(2, 1)));
+        assert_eq!(grid.position(Point::new(-1, 1)), None);
+        assert_eq!(grid.position(Point::new(1, 3)), None);
+    }
+
+    #[test]
+    fn draw_paths() {
+        let grid = Grid::parse(&lines("....\n....\n...."), ".").unwrap();
+        let path = [
+            Position::new(0, 0),
+            Position::new(2, 0),
+            Position::new(2, 2),
+            Position::new(1, 2),
+        ];
+        assert_eq!(grid.draw_path(&path).to_string(true), ".>>.\n..v.\n.<v.");
+        assert_eq!(grid.draw_path(&path[..1]), grid);
+
+        // Diagonal moves and repeated points are skipped, moves off the grid stop at the edge.
+        let bad = [
+            Position::new(0, 0),
+            Position::new(1, 1),
+            Position::new(1, 1),
+            Position::new(1, 5),
+            Position::new(3, 0),
+        ];
+        assert_eq!(grid.draw_path(&bad).to_string(true), "....\n....\n.v..");
+
+        let marked = grid.highlight([Position::new(3, 1), Position::new(4, 1)], '#');
+        assert_eq!(marked.to_string(true), "....\n...#\n....");
     }
 }
@@ -0,0 +1,191 @@
+// This module contains the coordinates used on and around grids.
+// A `Point` can be negative and do arithmetic, a `Position` is an index into a grid. Both are
+// ordered in reading order, so top to bottom and then left to right, like the cells of a grid.
+use num_traits::Signed;
+use std::cmp::Ordering;
+use std::num::TryFromIntError;
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+#[allow(unused)]
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    // Convert to a point of a wider type, e.g. from i32 to i128.
+    pub fn cast<U: From<T>>(self) -> Point<U> {
+        Point::new(self.x.into(), self.y.into())
+    }
+}
+
+#[allow(unused)]
+impl<T: Signed + Copy + Ord> Point<T> {
+    // The distance when only moving horizontally and vertically.
+    pub fn manhattan(self, other: Point<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // The distance when diagonal moves are allowed as well.
+    pub fn chebyshev(self, other: Point<T>) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    // Rotate 90 degrees clockwise around the origin, with y growing downwards like on a grid.
+    pub fn rotate_right(self) -> Point<T> {
+        Point::new(-self.y, self.x)
+    }
+
+    // Rotate 90 degrees counter-clockwise around the origin.
+    pub fn rotate_left(self) -> Point<T> {
+        Point::new(self.y, -self.x)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, scalar: T) -> Point<T> {
+        Point::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Ord> Ord for Point<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.y, &self.x).cmp(&(&other.y, &other.x))
+    }
+}
+
+impl<T: Ord> PartialOrd for Point<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[allow(unused)]
+impl Position {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Ord for Position {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Position {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// A point with a negative coordinate has no position.
+impl<T> TryFrom<Point<T>> for Position
+where
+    usize: TryFrom<T, Error = TryFromIntError>,
+{
+    type Error = TryFromIntError;
+
+    fn try_from(point: Point<T>) -> Result<Position, TryFromIntError> {
+        Ok(Position::new(point.x.try_into()?, point.y.try_into()?))
+    }
+}
+
+// A position too far out for the type of the point has no point.
+impl<T> TryFrom<Position> for Point<T>
+where
+    T: TryFrom<usize, Error = TryFromIntError>,
+{
+    type Error = TryFromIntError;
+
+    fn try_from(pos: Position) -> Result<Point<T>, TryFromIntError> {
+        Ok(Point::new(pos.x.try_into()?, pos.y.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 4);
+        assert_eq!(a + b, Point::new(2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point::new(5_i64, 0).manhattan(Point::default()), 5);
+
+        let up = Point::new(0, -1);
+        assert_eq!(up.rotate_right(), Point::new(1, 0));
+        assert_eq!(up.rotate_left(), Point::new(-1, 0));
+        assert_eq!(a.rotate_right().rotate_right(), -a);
+        assert_eq!(a.rotate_left().rotate_right(), a);
+        assert_eq!(a.cast::<i128>(), Point::new(3_i128, -2));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            Position::try_from(Point::new(2, 3)),
+            Ok(Position::new(2, 3))
+        );
+        assert!(Position::try_from(Point::new(-1, 3)).is_err());
+        assert_eq!(
+            Point::<i64>::try_from(Position::new(4, 5)),
+            Ok(Point::new(4, 5))
+        );
+        assert!(Point::<i8>::try_from(Position::new(400, 5)).is_err());
+    }
+
+    #[test]
+    fn reading_order() {
+        let mut points = vec![Point::new(1, 1), Point::new(0, 2), Point::new(2, 0)];
+        points.sort();
+        assert_eq!(
+            points,
+            vec![Point::new(2, 0), Point::new(1, 1), Point::new(0, 2)]
+        );
+        assert!(Position::new(5, 0) < Position::new(0, 1));
+    }
+}
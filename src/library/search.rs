@@ -0,0 +1,376 @@
+// Graph searches over any state space, given as a closure from a node to its successors.
+// BFS counts steps, Dijkstra and A* add up the costs of every move. All of them stop at the first
+// goal they take off the queue, so the distance map is only complete when no goal is reached.
+// Long searches check the cancellation token of the running day every so often, so they can time out.
+use crate::error::Result;
+use crate::library::cancel;
+use crate::library::containers::direction::Direction;
+use crate::library::containers::grid::{Grid, Position};
+use hashbrown::HashMap;
+use num_traits::Zero;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::{Add, RangeInclusive};
+
+// Reading the clock for every node is measurable on big searches, so the cancellation token is
+// only checked once every so many nodes.
+const CHECK_EVERY: usize = 1024;
+
+// Everything a search found out, from any of its start nodes.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    // The goal that was reached, and what it cost to get there.
+    pub goal: Option<(N, C)>,
+    // Every node from a start up to and including the goal, empty when no goal was reached.
+    pub path: Vec<N>,
+    // How many nodes were taken off the queue and expanded.
+    pub visited: usize,
+    // The cheapest known cost of every node that was reached.
+    pub distances: HashMap<N, C>,
+    // The node every reached node was reached from, starts have none.
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N, C: Copy> Search<N, C> {
+    pub fn cost(&self) -> Option<C> {
+        self.goal.as_ref().map(|(_, cost)| *cost)
+    }
+}
+
+impl<N: Clone + Eq + Hash, C> Search<N, C> {
+    fn new(
+        goal: Option<(N, C)>,
+        visited: usize,
+        distances: HashMap<N, C>,
+        predecessors: HashMap<N, N>,
+    ) -> Self {
+        let mut search = Search {
+            goal,
+            path: Vec::new(),
+            visited,
+            distances,
+            predecessors,
+        };
+        if let Some((node, _)) = &search.goal {
+            search.path = search.path_to(node);
+        }
+        search
+    }
+
+    // Follow the links back from any reached node to the start it was reached from, so the route to a
+    // node other than the goal can be rebuilt as well. Empty for a node that was never reached.
+    pub fn path_to(&self, node: &N) -> Vec<N> {
+        if !self.distances.contains_key(node) {
+            return Vec::new();
+        }
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+// Find the goal in the fewest moves.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Result<Search<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(node) = queue.pop_front() {
+        if visited % CHECK_EVERY == 0 {
+            cancel::check()?;
+        }
+        visited += 1;
+        let distance = distances[&node];
+        if is_goal(&node) {
+            return Ok(Search::new(
+                Some((node, distance)),
+                visited,
+                distances,
+                predecessors,
+            ));
+        }
+
+        for next in successors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Ok(Search::new(None, visited, distances, predecessors))
+}
+
+// A node on the queue of A*, the cheapest estimate comes off first.
+struct Queued<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&other.estimate, &other.cost).cmp(&(&self.estimate, &self.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+// Find the cheapest way to a goal, where successors come with the cost of moving to them.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Result<Search<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::zero(), is_goal)
+}
+
+// Find the cheapest way to a goal, guided by a heuristic that never overestimates the remaining cost.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Result<Search<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut distances = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        distances.insert(start.clone(), C::zero());
+        queue.push(Queued {
+            estimate: heuristic(&start),
+            cost: C::zero(),
+            node: start,
+        });
+    }
+
+    let mut visited = 0;
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // A node can be queued several times, only its cheapest entry counts.
+        if distances.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if visited % CHECK_EVERY == 0 {
+            cancel::check()?;
+        }
+        visited += 1;
+        if is_goal(&node) {
+            return Ok(Search::new(
+                Some((node, cost)),
+                visited,
+                distances,
+                predecessors,
+            ));
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if distances.get(&next).is_some_and(|&best| best <= next_cost) {
+                continue;
+            }
+            distances.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), node.clone());
+            queue.push(Queued {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    Ok(Search::new(None, visited, distances, predecessors))
+}
+
+// The moves out of a grid cell, at most one in every direction. Kept in a fixed array, so expanding a
+// node does not allocate.
+pub type Moves<N> = std::iter::Flatten<std::array::IntoIter<Option<N>, 4>>;
+
+// Get the positions around a position that can be moved onto, for searches that count steps.
+pub fn neighbours<'a, T>(
+    grid: &'a Grid<T>,
+    passable: impl Fn(&T) -> bool + 'a,
+) -> impl Fn(&Position) -> Moves<Position> + 'a {
+    move |&pos| {
+        Direction::ALL
+            .map(|dir| {
+                grid.step(pos, dir)
+                    .filter(|next| grid.cell(next.y, next.x).is_some_and(&passable))
+            })
+            .into_iter()
+            .flatten()
+    }
+}
+
+// Get the positions around a position with the cost of entering them, None for a cell that can not be entered.
+pub fn weighted_neighbours<'a, T, C>(
+    grid: &'a Grid<T>,
+    cost: impl Fn(&T) -> Option<C> + 'a,
+) -> impl Fn(&Position) -> Moves<(Position, C)> + 'a {
+    move |&pos| {
+        Direction::ALL
+            .map(|dir| {
+                let next = grid.step(pos, dir)?;
+                Some((next, cost(grid.cell(next.y, next.x)?)?))
+            })
+            .into_iter()
+            .flatten()
+    }
+}
+
+// Walk in a straight line from a position, getting every position a number of steps away that is in
+// the given range, with the cost of entering every cell on the way added up. For crucible-like moves.
+pub fn straight<'a, T, C>(
+    grid: &'a Grid<T>,
+    pos: Position,
+    dir: Direction,
+    steps: RangeInclusive<usize>,
+    cost: impl Fn(&T) -> C + 'a,
+) -> impl Iterator<Item = (Position, C)> + 'a
+where
+    C: Copy + Zero + Add<Output = C> + 'a,
+{
+    let mut total = C::zero();
+    std::iter::successors(grid.step(pos, dir), move |&current| grid.step(current, dir))
+        .take(*steps.end())
+        .enumerate()
+        .filter_map(move |(i, next)| {
+            total = total + cost(grid.cell(next.y, next.x).unwrap());
+            steps.contains(&(i + 1)).then_some((next, total))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid {
+        let lines: Vec<String> = text.lines().map(String::from).collect();
+        Grid::parse(&lines, ".#S0123456789").unwrap()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let maze = grid("S.#\n#..\n...");
+        let goal = Position::new(0, 2);
+        let search = bfs(
+            [Position::new(0, 0)],
+            neighbours(&maze, |&c| c != '#'),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(search.cost(), Some(4));
+        assert_eq!(search.path.len(), 5);
+        assert_eq!(search.path.first(), Some(&Position::new(0, 0)));
+        assert_eq!(search.path.last(), Some(&goal));
+        assert!(search.visited <= 7);
+
+        let walled = bfs(
+            [Position::new(0, 0)],
+            neighbours(&maze, |&c| c == '.'),
+            |&p| p == Position::new(2, 0),
+        )
+        .unwrap();
+        assert!(walled.goal.is_none() && walled.path.is_empty());
+        assert_eq!(walled.distances.len(), 7);
+        assert_eq!(walled.distances[&Position::new(1, 2)], 3);
+        assert_eq!(
+            walled.path_to(&Position::new(1, 2)),
+            vec![
+                Position::new(0, 0),
+                Position::new(1, 0),
+                Position::new(1, 1),
+                Position::new(1, 2)
+            ]
+        );
+        assert!(walled.path_to(&Position::new(2, 0)).is_empty());
+    }
+
+    #[test]
+    fn cheapest_paths() {
+        let costs = grid("119\n919\n111");
+        let cost = |c: &char| c.to_digit(10);
+        let goal = Position::new(2, 2);
+        let search = dijkstra(
+            [Position::new(0, 0)],
+            weighted_neighbours(&costs, cost),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(search.cost(), Some(4));
+        assert_eq!(
+            search.path,
+            vec![
+                Position::new(0, 0),
+                Position::new(1, 0),
+                Position::new(1, 1),
+                Position::new(1, 2),
+                Position::new(2, 2)
+            ]
+        );
+
+        let guided = astar(
+            [Position::new(0, 0)],
+            weighted_neighbours(&costs, cost),
+            |p| (goal.x - p.x + goal.y - p.y) as u32,
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(guided.cost(), search.cost());
+        assert!(guided.visited <= search.visited);
+    }
+
+    #[test]
+    fn straight_lines() {
+        let costs = grid("1234\n5678");
+        let digit = |c: &char| c.to_digit(10).unwrap();
+        let start = Position::new(0, 0);
+        let moves = |dir, steps| straight(&costs, start, dir, steps, digit).collect::<Vec<_>>();
+        assert_eq!(
+            moves(Direction::Right, 2..=5),
+            vec![(Position::new(2, 0), 5), (Position::new(3, 0), 9)]
+        );
+        assert_eq!(moves(Direction::Up, 1..=3), vec![]);
+    }
+}
@@ -1,2 +1,3 @@
 // Contains various utility modules that are part of the library.
-pub mod files;
\ No newline at end of file
+pub mod files;
+pub mod parsing;
\ No newline at end of file
@@ -5,14 +5,17 @@ use std::{
 	path::Path,
 };
 
-// Read a file from a given path and return a vector of strings.
-#[allow(unused)]
-pub fn read_file(path: &str) -> Vec<String> {
-    if let Ok(lines) = read_lines(path) {
-        lines.map(|line| line.unwrap()).collect()
-    } else {
-        panic!("Could not read file: {path}");
-    }
+// Read a file from a given path and return its lines, or the error when it can not be read.
+pub fn try_read_file<P>(path: P) -> io::Result<Vec<String>>
+where
+    P: AsRef<Path>,
+{
+    read_lines(path)?.collect()
+}
+
+// Read every line from a reader, such as a locked stdin.
+pub fn read_from<R: BufRead>(reader: R) -> io::Result<Vec<String>> {
+    reader.lines().collect()
 }
 
 // Read lines from a file at a given file-name.
@@ -23,4 +26,4 @@ where
 {
     let file = File::open(filename)?;
     Ok(io::BufReader::new(file).lines())
-}
\ No newline at end of file
+}
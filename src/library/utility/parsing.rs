@@ -0,0 +1,98 @@
+// Helpers for parsing puzzle inputs line by line, while keeping track of where every token came from.
+// That way a malformed input points at the exact line and column instead of panicking.
+use crate::error::{Error, Result};
+use std::str::FromStr;
+
+// A single line of input alongside its line number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+// Number every line of the input, starting at 1.
+pub fn lines(data: &[String]) -> impl Iterator<Item = Line<'_>> {
+    data.iter().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    // Get the column of a token that was split off this line, or 1 if it is not part of it.
+    pub fn column(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() {
+            offset + 1
+        } else {
+            1
+        }
+    }
+
+    // Create a parse error pointing at the given token.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> Error {
+        Error::parse(self.number, self.column(token), message)
+    }
+
+    // Create an error for an unexpected grid character, at a 0-based index into the line.
+    pub fn invalid_char(&self, index: usize, found: char) -> Error {
+        Error::InvalidGridChar {
+            line: self.number,
+            column: index + 1,
+            found,
+        }
+    }
+
+    // Parse a token that was split off this line.
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, format!("expected a number, found {token:?}")))
+    }
+
+    // Parse every whitespace separated number in a part of this line.
+    pub fn numbers<T: FromStr>(&self, text: &str) -> Result<Vec<T>> {
+        text.split_whitespace().map(|s| self.parse(s)).collect()
+    }
+
+    // Split this line around the first occurrence of a delimiter.
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str)> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(self.text, format!("expected {delimiter:?}")))
+    }
+
+    // Get the nth whitespace separated word of this line.
+    pub fn word(&self, n: usize) -> Result<&'a str> {
+        self.text.split_whitespace().nth(n).ok_or_else(|| {
+            Error::parse(
+                self.number,
+                self.text.len() + 1,
+                format!("expected at least {} words", n + 1),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_errors() {
+        let data = vec!["Card 1: 41 48".to_string(), "Card 2: 13 x2".to_string()];
+        let lines: Vec<_> = lines(&data).collect();
+
+        let (_, numbers) = lines[0].split_once(": ").unwrap();
+        assert_eq!(lines[0].numbers::<u32>(numbers).unwrap(), vec![41, 48]);
+
+        let (_, numbers) = lines[1].split_once(": ").unwrap();
+        let e = lines[1].numbers::<u32>(numbers).unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 12: expected a number, found \"x2\"");
+
+        assert_eq!(lines[0].word(1).unwrap(), "1:");
+        assert!(lines[0].word(4).is_err());
+        assert!(lines[0].split_once("|").is_err());
+    }
+}
@@ -0,0 +1,868 @@
+// Contains everything needed to drive the solutions, independent of any specific day.
+pub mod answer;
+pub mod cancel;
+pub mod cli;
+pub mod client;
+pub mod config;
+pub mod examples;
+pub mod history;
+pub mod memory;
+pub mod report;
+pub mod scaffold;
+pub mod select;
+pub mod solution;
+pub mod store;
+pub mod submit;
+pub mod timing;
+pub mod verify;
+
+#[cfg(test)]
+mod testserver;
+
+use crate::error::{Error, Result};
+use crate::library::utility;
+use answer::Answer;
+use cancel::{Timeouts, Token};
+use client::{Client, Fetched};
+use memory::{Memory, Usage};
+use rayon::prelude::*;
+use serde::Serialize;
+use solution::Runnable;
+use std::any::Any;
+use std::collections::{btree_map::Entry, BTreeMap};
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use store::AnswerStore;
+use submit::{Refusal, SubmissionLog, Verdict};
+use timing::Timings;
+use verify::Verification;
+
+// How a solution should be run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    // How many times every step is repeated, the median duration is kept.
+    pub repeats: usize,
+    // Only run this part, or both when it is not set.
+    pub part: Option<u8>,
+    // How long a day may take when running several days, a day that takes longer is reported as timed out.
+    pub timeouts: Timeouts,
+    // Also ask the solution for a drawing of how it found its answers.
+    pub debug: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            repeats: 1,
+            part: None,
+            timeouts: Timeouts::default(),
+            debug: false,
+        }
+    }
+}
+
+impl Options {
+    fn runs(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+// The answers and timings of a single solution run, parts that were not run have no answer.
+#[derive(Debug, Clone, Serialize)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub timings: Timings,
+    // Only counted with the `alloc-stats` feature, and not when days run concurrently.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
+    // The drawing of a day in debug mode, it is not timed and left out of the JSON outputs.
+    #[serde(skip)]
+    pub debug: Option<String>,
+}
+
+// A single day of a run in the JSON outputs, a day that failed is included with its error.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub timings: Option<Timings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
+    // The answers checked against the accepted ones, only for the real inputs.
+    pub verification: Option<Verification>,
+    pub error: Option<String>,
+}
+
+// The environment variable that overrides the directory real puzzle inputs are read from.
+pub const DATA_DIR_VAR: &str = "MISTLETOE_DATA";
+
+// Get the directory real puzzle inputs are read from, `data` unless overridden by the environment.
+pub fn data_dir() -> PathBuf {
+    std::env::var_os(DATA_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("data"))
+}
+
+// Get the path of the real puzzle input for a given solution.
+pub fn input_path(solution: &dyn Runnable) -> String {
+    Source::data().describe(solution)
+}
+
+// Get the path of the example input for a given solution.
+pub fn example_path(solution: &dyn Runnable) -> String {
+    Source::example().describe(solution)
+}
+
+// Label a solution with its year and day, e.g. "2023 Day 05".
+pub fn label(solution: &dyn Runnable) -> String {
+    format!("{} Day {:02}", solution.year(), solution.day())
+}
+
+// Where the input of a solution is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    // A directory containing a `<year>/dayNN.txt` file per day.
+    Dir(PathBuf),
+    // A single file, whatever day is run.
+    File(PathBuf),
+    // Standard input, which can only be read once.
+    Stdin,
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Source::data()
+    }
+}
+
+impl Source {
+    // The real puzzle inputs.
+    pub fn data() -> Self {
+        Source::Dir(data_dir())
+    }
+
+    // The example inputs from the puzzle descriptions.
+    pub fn example() -> Self {
+        Source::Dir(PathBuf::from("testdata"))
+    }
+
+    // Get the path of the input file for a given solution, stdin has none.
+    pub fn path(&self, solution: &dyn Runnable) -> Option<PathBuf> {
+        match self {
+            Source::Dir(dir) => Some(
+                dir.join(solution.year().to_string())
+                    .join(format!("day{:02}.txt", solution.day())),
+            ),
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+        }
+    }
+
+    // Describe where the input for a given solution comes from, for output and error messages.
+    pub fn describe(&self, solution: &dyn Runnable) -> String {
+        match self.path(solution) {
+            Some(path) => path.display().to_string(),
+            None => "<stdin>".to_string(),
+        }
+    }
+
+    // Check whether there is an input for a given solution, stdin always counts as available.
+    pub fn exists(&self, solution: &dyn Runnable) -> bool {
+        self.path(solution).is_none_or(|path| path.exists())
+    }
+
+    // Read the input lines for a given solution.
+    pub fn read(&self, solution: &dyn Runnable) -> io::Result<Vec<String>> {
+        match self.path(solution) {
+            Some(path) => utility::files::try_read_file(path),
+            None => utility::files::read_from(io::stdin().lock()),
+        }
+    }
+}
+
+// Time a single step, and count its allocations per run when the counting allocator is installed.
+fn measure<T>(repeats: usize, f: impl FnMut() -> T) -> (T, Duration, Usage) {
+    let ((value, duration), usage) = memory::measure(|| timing::measure(repeats, f));
+    let runs = repeats.max(1) as u64;
+    let usage = Usage {
+        allocations: usage.allocations / runs,
+        bytes: usage.bytes / runs,
+        ..usage
+    };
+    (value, duration, usage)
+}
+
+// Run a solution against the given input, timing parse, part 1 and part 2 separately.
+// Every step is repeated and the median duration is kept, the first error aborts the run.
+pub fn execute(solution: &dyn Runnable, data: &[String], options: &Options) -> Result<Outcome> {
+    let repeats = options.repeats;
+    let mut timings = Timings::default();
+    let mut memory = Memory::default();
+
+    let (input, parse, usage) = measure(repeats, || solution.parse(data));
+    let input = input?;
+    timings.parse = parse;
+    memory.parse = usage;
+
+    let part1 = options
+        .runs(1)
+        .then(|| {
+            let (answer, duration, usage) = measure(repeats, || solution.part1(&input));
+            timings.part1 = duration;
+            memory.part1 = usage;
+            answer
+        })
+        .transpose()?;
+
+    let part2 = options
+        .runs(2)
+        .then(|| {
+            let (answer, duration, usage) = measure(repeats, || solution.part2(&input));
+            timings.part2 = duration;
+            memory.part2 = usage;
+            answer
+        })
+        .transpose()?;
+
+    let debug = if options.debug {
+        solution.debug(&input)?
+    } else {
+        None
+    };
+
+    Ok(Outcome {
+        year: solution.year(),
+        day: solution.day(),
+        input: String::new(),
+        part1,
+        part2,
+        timings,
+        memory: memory::enabled().then_some(memory),
+        debug,
+    })
+}
+
+// Run a single solution against the input from the given source.
+pub fn run(solution: &dyn Runnable, source: &Source, options: &Options) -> Result<Outcome> {
+    let data = source.read(solution)?;
+    Ok(Outcome {
+        input: source.describe(solution),
+        ..execute(solution, &data, options)?
+    })
+}
+
+// The stack every day gets on its own thread, about what the main thread usually has.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+// Run a single solution on a thread of its own, giving up on it once its time budget is used up.
+// Solutions that check the cancellation token stop by themselves, any other keeps its thread busy
+// in the background while the run carries on with the next day.
+pub fn run_within(
+    solution: &'static dyn Runnable,
+    source: &Source,
+    options: &Options,
+    budget: Duration,
+) -> Result<Outcome> {
+    let data = source.read(solution)?;
+    let options = options.clone();
+    let token = Token::new(budget);
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::Builder::new()
+        .name(label(solution))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let outcome = cancel::with(token, || execute(solution, &data, &options));
+            // The run may have stopped waiting for this day already.
+            let _ = sender.send(outcome);
+        })?;
+
+    match receiver.recv_timeout(budget) {
+        Ok(outcome) => Ok(Outcome {
+            input: source.describe(solution),
+            ..outcome?
+        }),
+        Err(RecvTimeoutError::Timeout) => Err(Error::TimedOut(budget)),
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(panic) => Err(Error::Panicked(panic_message(panic))),
+            Ok(()) => unreachable!("A finished day always sends its outcome"),
+        },
+    }
+}
+
+// Get the message a panic was raised with, as far as it was raised with a string.
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(message) => *message,
+        Err(panic) => panic
+            .downcast_ref::<&str>()
+            .map_or("no message", |message| message)
+            .to_string(),
+    }
+}
+
+// The results of running several days, in the order the days were given.
+pub struct Batch<'a> {
+    pub results: Vec<(&'a dyn Runnable, Result<Outcome>)>,
+    // Time from starting the first day until the last one finished.
+    pub wall: Duration,
+    // The wall time of every day on its own, added up. This is not CPU time, a day that runs
+    // on several threads itself still only counts once.
+    pub summed: Duration,
+}
+
+// A single day of a batch, alongside how long the whole run took including reading the input.
+type Timed<'a> = (&'a dyn Runnable, Result<Outcome>, Duration);
+
+fn run_timed(
+    solution: &'static dyn Runnable,
+    source: &Source,
+    options: &Options,
+) -> Timed<'static> {
+    let start = Instant::now();
+    // A day that panics fails on its own like any other error, the rest of the run carries on.
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        match options.timeouts.for_day(solution.year(), solution.day()) {
+            Some(budget) => run_within(solution, source, options, budget),
+            None => run(solution, source, options),
+        }
+    }))
+    .unwrap_or_else(|panic| Err(Error::Panicked(panic_message(panic))));
+    (solution, outcome, start.elapsed())
+}
+
+// Run the given days one after another.
+pub fn run_all(
+    solutions: &[&'static dyn Runnable],
+    source: &Source,
+    options: &Options,
+) -> Batch<'static> {
+    let start = Instant::now();
+    let timed: Vec<_> = solutions
+        .iter()
+        .map(|&s| run_timed(s, source, options))
+        .collect();
+    batch(timed, start.elapsed())
+}
+
+// Run whole days concurrently on a thread pool, with the given number of threads or one per core.
+// Days only return their outcome without printing, so the results can be printed in day order afterwards.
+pub fn run_parallel(
+    solutions: &[&'static dyn Runnable],
+    source: &Source,
+    options: &Options,
+    threads: Option<usize>,
+) -> Result<Batch<'static>> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.unwrap_or(0))
+        .build()
+        .map_err(io::Error::other)?;
+
+    let start = Instant::now();
+    let mut timed: Vec<_> = pool.install(|| {
+        solutions
+            .par_iter()
+            .map(|&s| run_timed(s, source, options))
+            .collect()
+    });
+    // The allocation counters are shared, so the memory of days running at the same time is mixed up.
+    for (_, outcome, _) in &mut timed {
+        if let Ok(outcome) = outcome {
+            outcome.memory = None;
+        }
+    }
+    Ok(batch(timed, start.elapsed()))
+}
+
+fn batch(timed: Vec<Timed<'_>>, wall: Duration) -> Batch<'_> {
+    let summed = timed.iter().map(|(_, _, elapsed)| *elapsed).sum();
+    let results = timed
+        .into_iter()
+        .map(|(s, outcome, _)| (s, outcome))
+        .collect();
+    Batch {
+        results,
+        wall,
+        summed,
+    }
+}
+
+// Print how long a batch took compared to running every day back to back.
+pub fn print_speedup(batch: &Batch) {
+    let speedup = batch.summed.as_secs_f64() / batch.wall.as_secs_f64().max(f64::EPSILON);
+    println!(
+        "Wall clock: {} | Summed wall time: {} | Speedup: {speedup:.2}x",
+        timing::format(batch.wall),
+        timing::format(batch.summed)
+    );
+}
+
+// Turn the results of a batch into records, checking the answers against the store of their year if asked to.
+pub fn records(batch: &Batch, source: &Source, verify: bool) -> io::Result<Vec<Record>> {
+    let mut stores: BTreeMap<u32, AnswerStore> = BTreeMap::new();
+    let mut records = Vec::new();
+
+    for (solution, outcome) in &batch.results {
+        let (year, day) = (solution.year(), solution.day());
+        let input = source.describe(*solution);
+        let record = match outcome {
+            Ok(outcome) => {
+                let verification = if verify {
+                    if let Entry::Vacant(e) = stores.entry(year) {
+                        e.insert(AnswerStore::load(store::path_for(year))?);
+                    }
+                    Some(verify::verification(outcome, &stores[&year]))
+                } else {
+                    None
+                };
+                Record {
+                    year,
+                    day,
+                    input,
+                    part1: outcome.part1.clone(),
+                    part2: outcome.part2.clone(),
+                    timings: Some(outcome.timings),
+                    memory: outcome.memory,
+                    verification,
+                    error: None,
+                }
+            }
+            Err(e) => Record {
+                year,
+                day,
+                input,
+                part1: None,
+                part2: None,
+                timings: None,
+                memory: None,
+                verification: None,
+                error: Some(e.to_string()),
+            },
+        };
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+// Print both stars and the time each step took.
+pub fn print_outcome(outcome: &Outcome) {
+    let t = &outcome.timings;
+    println!("{} Day {:02}:", outcome.year, outcome.day);
+    if let Some(answer) = &outcome.part1 {
+        println!("Star 1: {answer}");
+    }
+    if let Some(answer) = &outcome.part2 {
+        println!("Star 2: {answer}");
+    }
+    println!(
+        "Parse: {} | Part 1: {} | Part 2: {} | Total: {}",
+        timing::format(t.parse),
+        timing::format(t.part1),
+        timing::format(t.part2),
+        timing::format(t.total())
+    );
+    if let Some(memory) = &outcome.memory {
+        print_memory(memory);
+    }
+    if let Some(drawing) = &outcome.debug {
+        println!("{drawing}");
+    }
+    println!();
+}
+
+// Print the allocations of every step, e.g. "Parse: 12 allocs, 1.50 KiB, peak 1.00 KiB".
+fn print_memory(memory: &Memory) {
+    let step = |name: &str, usage: &Usage| {
+        format!(
+            "{name}: {} allocs, {}, peak {}",
+            usage.allocations,
+            memory::format(usage.bytes),
+            memory::format(usage.peak_bytes)
+        )
+    };
+    println!(
+        "{} | {} | {}",
+        step("Parse", &memory.parse),
+        step("Part 1", &memory.part1),
+        step("Part 2", &memory.part2)
+    );
+}
+
+// Print only the answers, one per line.
+pub fn print_quiet(outcome: &Outcome) {
+    for answer in [&outcome.part1, &outcome.part2].into_iter().flatten() {
+        println!("{answer}");
+    }
+}
+
+// Print a per-day table of timings, followed by the total across all days.
+pub fn print_summary(outcomes: &[Outcome]) {
+    println!(
+        "{:<8} {:>12} {:>12} {:>12} {:>12}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    let mut total = Timings::default();
+    for outcome in outcomes {
+        let t = outcome.timings;
+        println!(
+            "{:<8} {:>12} {:>12} {:>12} {:>12}",
+            format!("{}/{:02}", outcome.year, outcome.day),
+            timing::format(t.parse),
+            timing::format(t.part1),
+            timing::format(t.part2),
+            timing::format(t.total())
+        );
+        total = total + t;
+    }
+
+    println!(
+        "{:<8} {:>12} {:>12} {:>12} {:>12}",
+        "All",
+        timing::format(total.parse),
+        timing::format(total.part1),
+        timing::format(total.part2),
+        timing::format(total.total())
+    );
+}
+
+// Verify every given solution against the accepted answers of its year, printing a line per day.
+// Days without an input are skipped, returns false if any previously accepted answer changed
+// or a day failed to run.
+pub fn verify_all<'a>(
+    solutions: impl IntoIterator<Item = &'a dyn Runnable>,
+    source: &Source,
+) -> io::Result<bool> {
+    let mut stores: BTreeMap<u32, AnswerStore> = BTreeMap::new();
+    let mut ok = true;
+
+    for solution in solutions {
+        if !source.exists(solution) {
+            let path = source.describe(solution);
+            println!("{}: skipped, no input at {path}", label(solution));
+            continue;
+        }
+
+        let year = solution.year();
+        if let Entry::Vacant(e) = stores.entry(year) {
+            e.insert(AnswerStore::load(store::path_for(year))?);
+        }
+
+        let report = source
+            .read(solution)
+            .map_err(Into::into)
+            .and_then(|data| verify::verify(solution, &data, &stores[&year]));
+        match report {
+            Ok(report) => {
+                verify::print_report(&report);
+                ok &= !report.failed();
+            }
+            Err(e) => {
+                println!("{}: error: {e}", label(solution));
+                ok = false;
+            }
+        }
+    }
+
+    Ok(ok)
+}
+
+// Run a solution against its real input and record both answers as accepted.
+pub fn accept(solution: &dyn Runnable, source: &Source) -> Result<Outcome> {
+    let mut store = AnswerStore::load(store::path_for(solution.year()))?;
+    let outcome = run(solution, source, &Options::default())?;
+    for (part, answer) in [(1, &outcome.part1), (2, &outcome.part2)] {
+        if let Some(answer) = answer {
+            store.accept(outcome.day, part, answer.clone());
+        }
+    }
+    store.save()?;
+    Ok(outcome)
+}
+
+// Run a solution against its real input and send the answer of one part, unless the submission log
+// shows it can not be right. Every answer that is sent is logged, and a correct one is also accepted.
+pub fn submit(
+    solution: &dyn Runnable,
+    part: u8,
+    source: &Source,
+    client: &Client,
+) -> Result<(Answer, std::result::Result<Verdict, Refusal>)> {
+    let options = Options {
+        part: Some(part),
+        ..Options::default()
+    };
+    let outcome = run(solution, source, &options)?;
+    let answer = match [outcome.part1, outcome.part2].into_iter().flatten().next() {
+        Some(answer) if answer.is_implemented() => answer,
+        _ => return Err(Error::unsolvable(format!("part {part} is not implemented"))),
+    };
+
+    let (year, day) = (solution.year(), solution.day());
+    let mut log = SubmissionLog::load(submit::path_for(year))?;
+    if let Err(refusal) = log.check(day, part, &answer) {
+        return Ok((answer, Err(refusal)));
+    }
+
+    let verdict = client.submit(year, day, part, &answer)?;
+    log.record(day, part, answer.clone(), verdict.clone());
+    log.save()?;
+
+    if verdict == Verdict::Correct {
+        let mut store = AnswerStore::load(store::path_for(year))?;
+        store.accept(day, part, answer.clone());
+        store.save()?;
+    }
+    Ok((answer, Ok(verdict)))
+}
+
+// Make sure the inputs of the given days are in a data directory, downloading the missing ones.
+// Every day is reported on stderr, returns false if any input could not be downloaded.
+pub fn fetch_all<'a>(
+    solutions: impl IntoIterator<Item = &'a dyn Runnable>,
+    dir: &Path,
+    client: &Client,
+) -> bool {
+    let source = Source::Dir(dir.to_path_buf());
+    let mut ok = true;
+    for solution in solutions {
+        let path = source
+            .path(solution)
+            .expect("A directory has a file per day");
+        match client.fetch_input(solution.year(), solution.day(), &path) {
+            Ok(Fetched::Cached) => eprintln!("{}: cached at {}", label(solution), path.display()),
+            Ok(Fetched::Downloaded) => {
+                eprintln!("{}: downloaded to {}", label(solution), path.display())
+            }
+            Err(e) => {
+                eprintln!("{}: could not download the input: {e}", label(solution));
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+// Print a one-line description of every given solution.
+pub fn list<'a>(solutions: impl IntoIterator<Item = &'a dyn Runnable>) {
+    for solution in solutions {
+        println!("{}", solution.describe());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions;
+
+    #[test]
+    fn execute_example() {
+        let solution = solutions::find(2023, 5).unwrap();
+        let data = utility::files::try_read_file("testdata/2023/day05.txt").unwrap();
+        let options = Options {
+            repeats: 3,
+            ..Options::default()
+        };
+        let outcome = execute(solution, &data, &options).unwrap();
+        assert_eq!((outcome.year, outcome.day), (2023, 5));
+        assert_eq!(outcome.part1, Some(Answer::from(35)));
+        assert_eq!(outcome.part2, Some(Answer::from(46)));
+    }
+
+    #[test]
+    fn execute_single_part() {
+        let solution = solutions::find(2023, 5).unwrap();
+        let options = Options {
+            part: Some(2),
+            ..Options::default()
+        };
+        let outcome = run(solution, &Source::example(), &options).unwrap();
+        assert_eq!(outcome.input, "testdata/2023/day05.txt");
+        assert_eq!(outcome.part1, None);
+        assert_eq!(outcome.part2, Some(Answer::from(46)));
+    }
+
+    #[test]
+    fn sources() {
+        let solution = solutions::find(2023, 5).unwrap();
+        let file = Source::File(PathBuf::from("testdata/2023/day05.txt"));
+        assert_eq!(file.path(solution), Source::example().path(solution));
+        assert_eq!(Source::Stdin.describe(solution), "<stdin>");
+        assert!(Source::Dir(PathBuf::from("testdata")).exists(solution));
+        assert!(!Source::Dir(PathBuf::from("missing")).exists(solution));
+        assert!(run(
+            solution,
+            &Source::File(PathBuf::from("missing")),
+            &Options::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn parallel_keeps_day_order() {
+        let selected: Vec<_> = solutions::filter(|s| s.year() == 2023 && s.day() <= 6).collect();
+        let batch =
+            run_parallel(&selected, &Source::example(), &Options::default(), Some(3)).unwrap();
+        let days: Vec<u32> = batch.results.iter().map(|(s, _)| s.day()).collect();
+        assert_eq!(days, vec![1, 2, 3, 4, 5, 6]);
+        assert!(batch.results.iter().all(|(_, outcome)| outcome.is_ok()));
+        assert!(batch.summed > Duration::ZERO && batch.wall > Duration::ZERO);
+    }
+
+    // Sleeps for a while in part 1, checking the cancellation token only when asked to.
+    struct Sleepy(bool);
+
+    impl solution::Solution for Sleepy {
+        type Input = ();
+
+        fn parse(&self, _: &[String]) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(&self, _: &()) -> Result<Answer> {
+            for _ in 0..50 {
+                if self.0 {
+                    cancel::check()?;
+                }
+                thread::sleep(Duration::from_millis(10));
+            }
+            Ok(Answer::from(1))
+        }
+
+        fn part2(&self, _: &()) -> Result<Answer> {
+            Ok(Answer::from(2))
+        }
+
+        fn name(&self) -> &'static str {
+            "Sleepy"
+        }
+
+        fn day(&self) -> u32 {
+            if self.0 {
+                1
+            } else {
+                2
+            }
+        }
+
+        fn year(&self) -> u32 {
+            2015
+        }
+    }
+
+    static COOPERATIVE: Sleepy = Sleepy(true);
+    static STUBBORN: Sleepy = Sleepy(false);
+
+    #[test]
+    fn time_out_days() {
+        let selected: Vec<&'static dyn Runnable> =
+            vec![&COOPERATIVE, &STUBBORN, solutions::find(2023, 5).unwrap()];
+        let source = Source::File(PathBuf::from("testdata/2023/day05.txt"));
+        let options = Options {
+            timeouts: Timeouts {
+                default: Some(Duration::from_millis(50)),
+                days: vec![("2023:5".parse().unwrap(), Duration::from_secs(60))],
+            },
+            ..Options::default()
+        };
+
+        let batch = run_all(&selected, &source, &options);
+        let timed_out = |i: usize| matches!(batch.results[i].1, Err(Error::TimedOut(_)));
+        assert!(timed_out(0) && timed_out(1));
+        let outcome = batch.results[2].1.as_ref().unwrap();
+        assert_eq!(outcome.part1, Some(Answer::from(35)));
+        assert_eq!(outcome.input, "testdata/2023/day05.txt");
+    }
+
+    // Panics in part 1, like a solution indexing out of bounds on an unexpected input.
+    struct Panicky;
+
+    impl solution::Solution for Panicky {
+        type Input = ();
+
+        fn parse(&self, _: &[String]) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(&self, _: &()) -> Result<Answer> {
+            panic!("unexpected input")
+        }
+
+        fn part2(&self, _: &()) -> Result<Answer> {
+            Ok(Answer::from(2))
+        }
+
+        fn name(&self) -> &'static str {
+            "Panicky"
+        }
+
+        fn day(&self) -> u32 {
+            3
+        }
+
+        fn year(&self) -> u32 {
+            2015
+        }
+    }
+
+    static PANICKY: Panicky = Panicky;
+
+    #[test]
+    fn panicking_days_fail_alone() {
+        let selected: Vec<&'static dyn Runnable> =
+            vec![&PANICKY, solutions::find(2023, 5).unwrap()];
+        let source = Source::File(PathBuf::from("testdata/2023/day05.txt"));
+        let within = Options {
+            timeouts: Timeouts {
+                default: Some(Duration::from_secs(60)),
+                days: Vec::new(),
+            },
+            ..Options::default()
+        };
+
+        for options in [Options::default(), within] {
+            let batch = run_all(&selected, &source, &options);
+            assert!(matches!(
+                &batch.results[0].1,
+                Err(Error::Panicked(message)) if message == "unexpected input"
+            ));
+            assert!(batch.results[1].1.is_ok());
+        }
+    }
+
+    #[test]
+    fn records_include_failures() {
+        let selected: Vec<_> =
+            solutions::filter(|s| s.year() == 2023 && [5, 17].contains(&s.day())).collect();
+        let source = Source::File(PathBuf::from("testdata/2023/day05.txt"));
+        let batch = run_all(&selected, &source, &Options::default());
+        let records = records(&batch, &source, false).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].part1, Some(Answer::from(35)));
+        assert_eq!(records[0].input, "testdata/2023/day05.txt");
+        assert!(records[0].timings.is_some() && records[0].error.is_none());
+        assert!(records[1]
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("invalid grid character"));
+
+        let json = serde_json::to_value(&records[0]).unwrap();
+        assert_eq!(json["year"], 2023);
+        assert_eq!(json["part2"], 46);
+        assert!(json["timings"]["parse_ns"].is_u64());
+        assert!(json["verification"].is_null());
+    }
+}
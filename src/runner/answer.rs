@@ -0,0 +1,178 @@
+// This module contains the answer type returned by every part of every solution.
+// Integers of any width are stored as an i128, so answers compare equal regardless of the type they were computed in.
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    // The part has not been solved yet.
+    Unimplemented,
+}
+
+impl Answer {
+    pub fn is_implemented(&self) -> bool {
+        !matches!(self, Answer::Unimplemented)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unimplemented => write!(f, "Not implemented"),
+        }
+    }
+}
+
+// Implement conversions and comparisons for every integer type that fits losslessly into an i128.
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    matches!(self, Answer::Number(n) if *n == *other as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+// A u128 only becomes text when it does not fit into an i128, so it still compares and prints the same way.
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Answer::Number(n) => other.parse::<i128>() == Ok(*n),
+            Answer::Text(s) => s == other,
+            Answer::Unimplemented => false,
+        }
+    }
+}
+
+// Numbers are written as JSON numbers when they fit, and as strings otherwise, unimplemented answers are null.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => {
+                if let Ok(n) = i64::try_from(*n) {
+                    serializer.serialize_i64(n)
+                } else if let Ok(n) = u64::try_from(*n) {
+                    serializer.serialize_u64(n)
+                } else {
+                    serializer.serialize_str(&n.to_string())
+                }
+            }
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::Unimplemented => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+struct AnswerVisitor;
+
+impl<'de> de::Visitor<'de> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer, a string or null")
+    }
+
+    fn visit_i64<E: de::Error>(self, n: i64) -> Result<Answer, E> {
+        Ok(Answer::from(n))
+    }
+
+    fn visit_u64<E: de::Error>(self, n: u64) -> Result<Answer, E> {
+        Ok(Answer::from(n))
+    }
+
+    // Strings holding an integer are read back as numbers, which is how large numbers are written.
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Answer, E> {
+        Ok(match s.parse::<i128>() {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(s.to_string()),
+        })
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::Unimplemented)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::Unimplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(-42i64).to_string(), "-42");
+        assert_eq!(Answer::from("LLR").to_string(), "LLR");
+        assert_eq!(Answer::Unimplemented.to_string(), "Not implemented");
+    }
+
+    #[test]
+    fn equality_across_widths() {
+        assert_eq!(Answer::from(35u64), Answer::from(35i32));
+        assert_eq!(Answer::from(952408144115i128), 952408144115usize);
+        assert_eq!(Answer::from(u128::MAX), u128::MAX.to_string().as_str());
+        assert_ne!(Answer::from(0), Answer::Unimplemented);
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let answers = vec![
+            Answer::from(46u64),
+            Answer::from(-7i32),
+            Answer::from(i128::MAX),
+            Answer::from("text"),
+            Answer::Unimplemented,
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            json,
+            r#"[46,-7,"170141183460469231731687303715884105727","text",null]"#
+        );
+        let back: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, answers);
+    }
+}
@@ -0,0 +1,48 @@
+// This module decides how long every day may run before the runner gives up on it.
+// The token that enforces a budget lives in the library, so solutions can check it without
+// depending on the runner. It is re-exported here for the runner itself.
+use super::select::Target;
+pub use crate::library::cancel::{check, with, Token};
+use std::time::Duration;
+
+// The time budget of every day: a default, and overrides for some days.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Timeouts {
+    pub default: Option<Duration>,
+    // When several overrides match a day, the most specific one wins, e.g. "2023:5" over "5".
+    pub days: Vec<(Target, Duration)>,
+}
+
+impl Timeouts {
+    pub fn for_day(&self, year: u32, day: u32) -> Option<Duration> {
+        self.days
+            .iter()
+            .filter(|(target, _)| target.matches(year, day))
+            .max_by_key(|(target, _)| (target.year.is_some(), target.days.is_some()))
+            .map(|(_, budget)| *budget)
+            .or(self.default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeout_per_day() {
+        let secs = Duration::from_secs;
+        let timeouts = Timeouts {
+            default: Some(secs(10)),
+            days: vec![
+                ("2023:5".parse().unwrap(), secs(30)),
+                ("5".parse().unwrap(), secs(60)),
+                ("2023".parse().unwrap(), secs(20)),
+            ],
+        };
+        assert_eq!(timeouts.for_day(2023, 5), Some(secs(30)));
+        assert_eq!(timeouts.for_day(2022, 5), Some(secs(60)));
+        assert_eq!(timeouts.for_day(2023, 6), Some(secs(20)));
+        assert_eq!(timeouts.for_day(2022, 6), Some(secs(10)));
+        assert_eq!(Timeouts::default().for_day(2023, 5), None);
+    }
+}
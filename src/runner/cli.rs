@@ -0,0 +1,667 @@
+// The command line interface of the runner, it turns arguments into calls into the rest of the runner.
+use super::client::Client;
+use super::config::Config;
+use super::select::Target;
+use super::solution::Runnable;
+use super::submit::Verdict;
+use super::{examples, history, report, scaffold, Options, Outcome, Source};
+use crate::solutions;
+use clap::{Args, Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "mistletoe",
+    about = "Run, time and verify Advent of Code solutions."
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(long, global = true, help = "Only select solutions of this year")]
+    year: Option<u32>,
+
+    #[arg(
+        long,
+        short,
+        global = true,
+        conflicts_with_all = ["json", "jsonl"],
+        help = "Only print the answers"
+    )]
+    quiet: bool,
+
+    #[arg(long, global = true, help = "Print the results as JSON")]
+    json: bool,
+
+    #[arg(
+        long,
+        global = true,
+        conflicts_with = "json",
+        help = "Print the results as JSON Lines, one record per day"
+    )]
+    jsonl: bool,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "DIR",
+        help = "Read the real inputs from this directory instead of data/"
+    )]
+    data: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    #[command(about = "Run solutions and print their answers")]
+    Run(RunArgs),
+    #[command(about = "Time solutions over several repeats and print a summary table")]
+    Bench(RunArgs),
+    #[command(
+        name = "perf-report",
+        about = "Show how the benchmark timings developed and flag regressions"
+    )]
+    PerfReport {
+        #[command(flatten)]
+        selection: Selection,
+        #[arg(
+            long,
+            value_name = "COMMIT",
+            help = "Compare against this commit instead of the one before the latest"
+        )]
+        baseline: Option<String>,
+        #[arg(
+            long,
+            default_value_t = 10.0,
+            help = "Flag days that slowed down by more than this many percent"
+        )]
+        threshold: f64,
+    },
+    #[command(about = "Write stars, timings and badges into the README, from local data only")]
+    Report {
+        #[command(flatten)]
+        selection: Selection,
+        #[arg(
+            long,
+            default_value = "README.md",
+            help = "The README with the AOC TILES markers to update"
+        )]
+        readme: PathBuf,
+        #[arg(
+            long,
+            default_value = "media",
+            help = "The directory to write the badges to"
+        )]
+        media: PathBuf,
+    },
+    #[command(about = "Check the answers against the accepted answers")]
+    Verify(Selection),
+    #[command(about = "Record the current answers as accepted")]
+    Accept(Selection),
+    #[command(about = "Create a new day from the template and register it")]
+    New {
+        #[arg(
+            help = "The day to create, e.g. 5 or 2022:5, in the latest year when no year is given"
+        )]
+        day: Target,
+    },
+    #[command(about = "Submit the answer of a part to the website")]
+    Submit {
+        #[arg(help = "The day to submit, e.g. 5 or 2022:5")]
+        day: Target,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2), help = "The part to submit")]
+        part: u8,
+    },
+    #[command(
+        about = "Extract the example and its answers from a puzzle description into testdata/"
+    )]
+    Examples {
+        #[arg(help = "The day of the puzzle, e.g. 5 or 2022:5")]
+        day: Target,
+        #[arg(
+            long,
+            value_name = "FILE",
+            help = "Read a saved puzzle page instead of downloading it"
+        )]
+        html: Option<PathBuf>,
+        #[arg(long, help = "Replace examples that are already in testdata/")]
+        force: bool,
+    },
+    #[command(about = "Download the missing inputs into the data directory")]
+    Fetch(Selection),
+    #[command(about = "List the registered solutions")]
+    List(Selection),
+}
+
+#[derive(Debug, Default, Args)]
+struct Selection {
+    #[arg(help = "Days to select, e.g. 5, 1-5,12, 2022:1-25 or 2023, all days when not given")]
+    targets: Vec<Target>,
+}
+
+#[derive(Debug, Default, Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2), help = "Only run this part")]
+    part: Option<u8>,
+
+    #[arg(
+        long,
+        conflicts_with = "example",
+        help = "Read the input from this file instead, or from stdin when it is -"
+    )]
+    input: Option<PathBuf>,
+
+    #[arg(long, help = "Use the example input from testdata/")]
+    example: bool,
+
+    #[arg(long, help = "Repeat every step this many times and report the median")]
+    repeat: Option<usize>,
+
+    #[arg(long, help = "Run whole days concurrently and report the speedup")]
+    parallel: bool,
+
+    #[arg(
+        long,
+        requires = "parallel",
+        help = "Number of threads to run days on, one per core by default"
+    )]
+    jobs: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Do not append the timings to the benchmark history when benchmarking"
+    )]
+    no_record: bool,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        help = "Give up on a day after this long and carry on with the others, the per-day timeouts in mistletoe.toml still apply"
+    )]
+    timeout: Option<f64>,
+
+    #[arg(
+        long,
+        help = "Also print how the answers were found, e.g. the route through a grid, for days that can show it"
+    )]
+    debug: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Output {
+    Text,
+    Quiet,
+    Json,
+    JsonLines,
+}
+
+impl Output {
+    fn is_json(self) -> bool {
+        matches!(self, Output::Json | Output::JsonLines)
+    }
+}
+
+// Parse the command line and run the chosen command.
+pub fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match execute(cli) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(cli: Cli) -> Result<ExitCode, String> {
+    let output = if cli.json {
+        Output::Json
+    } else if cli.jsonl {
+        Output::JsonLines
+    } else if cli.quiet {
+        Output::Quiet
+    } else {
+        Output::Text
+    };
+
+    let data_dir = cli.data.unwrap_or_else(super::data_dir);
+    let data = Source::Dir(data_dir.clone());
+
+    match cli.command.unwrap_or(Command::Run(RunArgs::default())) {
+        Command::Run(args) => {
+            let selected = select(&args.selection, cli.year)?;
+            run(&selected, &args, data, 1, output, false)
+        }
+        Command::Bench(args) => {
+            let selected = select(&args.selection, cli.year)?;
+            run(&selected, &args, data, 10, output, true)
+        }
+        Command::PerfReport {
+            selection,
+            baseline,
+            threshold,
+        } => {
+            let selected = select(&selection, cli.year)?;
+            let records = history::load(history::PATH)
+                .map_err(|e| format!("could not read {}: {e}", history::PATH))?;
+            let trends: Vec<_> = history::trends(&records, baseline.as_deref())
+                .into_iter()
+                .filter(|t| {
+                    selected
+                        .iter()
+                        .any(|s| (s.year(), s.day()) == (t.year, t.day))
+                })
+                .collect();
+            if trends.is_empty() {
+                return Err(format!(
+                    "no benchmark history in {}, run bench first",
+                    history::PATH
+                ));
+            }
+            if history::print_report(&trends, threshold) {
+                Ok(ExitCode::SUCCESS)
+            } else {
+                eprintln!("Some days slowed down by more than {threshold}%.");
+                Ok(ExitCode::FAILURE)
+            }
+        }
+        Command::Report {
+            selection,
+            readme,
+            media,
+        } => {
+            let selected = select(&selection, cli.year)?;
+            let written = report::write(selected, &readme, &media)
+                .map_err(|e| format!("could not write the report: {e}"))?;
+            println!(
+                "Wrote {} badges and {}",
+                written.len() - 1,
+                readme.display()
+            );
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Verify(selection) => {
+            let selected = select(&selection, cli.year)?;
+            match super::verify_all(selected, &data) {
+                Ok(true) => Ok(ExitCode::SUCCESS),
+                Ok(false) => {
+                    eprintln!("Some previously accepted answers have changed.");
+                    Ok(ExitCode::FAILURE)
+                }
+                Err(e) => Err(format!("could not read the answer store: {e}")),
+            }
+        }
+        Command::Accept(selection) => {
+            for solution in select(&selection, cli.year)? {
+                let outcome = super::accept(solution, &data)
+                    .map_err(|e| format!("could not accept {}: {e}", super::label(solution)))?;
+                print(&[outcome], output, false);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::New { day: target } => {
+            let (year, day) = single_day(&target, cli.year, "new")?;
+            let changed = scaffold::create(Path::new("."), &data_dir, year, day)
+                .map_err(|e| e.to_string())?;
+            for path in changed {
+                println!("Wrote {}", path.display());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Submit { day: target, part } => {
+            let (year, day) = single_day(&target, cli.year, "submit")?;
+            let solution = solutions::find(year, day)
+                .ok_or_else(|| format!("day {day} is not registered for year {year}"))?;
+            let config = Config::load().map_err(|e| format!("could not read the config: {e}"))?;
+            let client = Client::new(&config);
+            if !data.exists(solution) {
+                super::fetch_all([solution], &data_dir, &client);
+            }
+
+            let label = super::label(solution);
+            let (answer, verdict) = super::submit(solution, part, &data, &client)
+                .map_err(|e| format!("could not submit {label} part {part}: {e}"))?;
+            match verdict {
+                Ok(verdict) => {
+                    println!("{label} part {part}: {answer} is {verdict}");
+                    if verdict == Verdict::Correct {
+                        return Ok(ExitCode::SUCCESS);
+                    }
+                }
+                Err(refusal) => println!("{label} part {part}: not submitting {answer}, {refusal}"),
+            }
+            Ok(ExitCode::FAILURE)
+        }
+        Command::Examples {
+            day: target,
+            html,
+            force,
+        } => {
+            let (year, day) = single_day(&target, cli.year, "examples")?;
+            let page = match html {
+                Some(path) => std::fs::read_to_string(&path)
+                    .map_err(|e| format!("could not read {}: {e}", path.display()))?,
+                None => {
+                    let config =
+                        Config::load().map_err(|e| format!("could not read the config: {e}"))?;
+                    Client::new(&config)
+                        .puzzle(year, day)
+                        .map_err(|e| format!("could not download the puzzle: {e}"))?
+                }
+            };
+            let written = examples::write(Path::new("testdata"), year, day, &page, force)
+                .map_err(|e| format!("could not extract the example: {e}"))?;
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Fetch(selection) => {
+            let selected = select(&selection, cli.year)?;
+            let config = Config::load().map_err(|e| format!("could not read the config: {e}"))?;
+            if super::fetch_all(selected, &data_dir, &Client::new(&config)) {
+                Ok(ExitCode::SUCCESS)
+            } else {
+                Ok(ExitCode::FAILURE)
+            }
+        }
+        Command::List(selection) => {
+            super::list(select(&selection, cli.year)?);
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+// Resolve a target naming exactly one day, in the latest registered year when no year is given.
+fn single_day(target: &Target, year: Option<u32>, command: &str) -> Result<(u32, u32), String> {
+    let day = match target.days.as_ref().map(|d| d.iter().collect::<Vec<_>>()) {
+        Some(days) if days.len() == 1 => days[0],
+        _ => return Err(format!("{command} takes a single day, e.g. 5 or 2022:5")),
+    };
+    let year = target
+        .year
+        .or(year)
+        .or(solutions::years().last())
+        .ok_or("no year given and no years registered yet, use e.g. 2023:1")?;
+    Ok((year, day))
+}
+
+// Resolve the selected days into registered solutions, every explicitly requested day has to exist.
+fn select(selection: &Selection, year: Option<u32>) -> Result<Vec<&'static dyn Runnable>, String> {
+    let in_year = |s: &dyn Runnable| year.is_none_or(|y| s.year() == y);
+    let targets = match &selection.targets[..] {
+        [] => &[Target::all()][..],
+        targets => targets,
+    };
+
+    let selected: Vec<_> =
+        solutions::filter(|s| in_year(s) && targets.iter().any(|t| t.matches(s.year(), s.day())))
+            .collect();
+
+    for target in targets {
+        let requested_year = target.year.or(year);
+        for day in target.days.iter().flat_map(|d| d.iter()) {
+            let found = selected
+                .iter()
+                .any(|s| s.day() == day && requested_year.is_none_or(|y| s.year() == y));
+            if !found {
+                return Err(format!(
+                    "day {day} is not registered for {}",
+                    describe_year(requested_year)
+                ));
+            }
+        }
+    }
+
+    if selected.is_empty() {
+        return Err(format!(
+            "no solutions registered for {}",
+            describe_year(year)
+        ));
+    }
+    Ok(selected)
+}
+
+fn describe_year(year: Option<u32>) -> String {
+    match year {
+        Some(year) => format!("year {year}"),
+        None => "any year".to_string(),
+    }
+}
+
+// Run the selected solutions, a summary table is printed when running more than one day or benchmarking.
+fn run(
+    selected: &[&'static dyn Runnable],
+    args: &RunArgs,
+    data: Source,
+    default_repeats: usize,
+    output: Output,
+    bench: bool,
+) -> Result<ExitCode, String> {
+    if args.input.is_some() && selected.len() != 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+
+    let config = Config::load().map_err(|e| format!("could not read the config: {e}"))?;
+    let mut timeouts = config
+        .timeouts()
+        .map_err(|e| format!("could not read the config: {e}"))?;
+    if let Some(seconds) = args.timeout {
+        let budget = Duration::try_from_secs_f64(seconds)
+            .map_err(|_| format!("invalid timeout {seconds}, expected a number of seconds"))?;
+        timeouts.default = Some(budget);
+    }
+
+    let options = Options {
+        repeats: args.repeat.unwrap_or(default_repeats),
+        part: args.part,
+        timeouts,
+        debug: args.debug,
+    };
+
+    let source = match &args.input {
+        Some(path) if path.as_os_str() == "-" => Source::Stdin,
+        Some(path) => Source::File(path.clone()),
+        None if args.example => Source::example(),
+        None => data,
+    };
+
+    // Missing real inputs are downloaded first, as long as there is a session to do it with.
+    if let Source::Dir(dir) = &source {
+        let missing: Vec<_> = selected
+            .iter()
+            .filter(|s| !source.exists(**s))
+            .copied()
+            .collect();
+        if !args.example && !missing.is_empty() && config.session.is_some() {
+            super::fetch_all(missing, dir, &Client::new(&config));
+        }
+    }
+
+    let batch = if args.parallel {
+        super::run_parallel(selected, &source, &options, args.jobs).map_err(|e| e.to_string())?
+    } else {
+        super::run_all(selected, &source, &options)
+    };
+
+    // A failing day is reported and skipped, so one broken solution does not hide the others.
+    let mut outcomes = Vec::new();
+    let mut failed = 0;
+    for (solution, outcome) in &batch.results {
+        let outcome = match outcome {
+            Ok(outcome) => outcome.clone(),
+            Err(e) => {
+                let input = source.describe(*solution);
+                eprintln!("{}: failed on {input}: {e}", super::label(*solution));
+                failed += 1;
+                continue;
+            }
+        };
+        if !output.is_json() && !bench {
+            print(std::slice::from_ref(&outcome), output, false);
+        }
+        outcomes.push(outcome);
+    }
+
+    if output.is_json() {
+        let verify = args.input.is_none() && !args.example;
+        let records = super::records(&batch, &source, verify)
+            .map_err(|e| format!("could not read the answer store: {e}"))?;
+        print_json(&records, output);
+    } else if bench || outcomes.len() > 1 {
+        print(&outcomes, output, true);
+    }
+
+    if args.parallel && output == Output::Text {
+        super::print_speedup(&batch);
+    }
+
+    if bench && records_history(args) && !outcomes.is_empty() {
+        let (commit, timestamp) = (history::current_commit(), history::now());
+        let records: Vec<_> = outcomes
+            .iter()
+            .map(|outcome| history::Record::new(outcome, &commit, timestamp))
+            .collect();
+        history::append(history::PATH, &records)
+            .map_err(|e| format!("could not append to {}: {e}", history::PATH))?;
+    }
+
+    if failed > 0 {
+        eprintln!("{failed} of {} days failed", selected.len());
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// Only timings of both parts of the real inputs are worth comparing over time, and only when every
+// day had the cores to itself, as days competing for them skew each other's timings.
+fn records_history(args: &RunArgs) -> bool {
+    !args.no_record
+        && args.input.is_none()
+        && !args.example
+        && args.part.is_none()
+        && !args.parallel
+}
+
+// Print a list of records as a single JSON array, or as one JSON object per line.
+fn print_json<T: serde::Serialize>(records: &[T], output: Output) {
+    if output == Output::JsonLines {
+        for record in records {
+            println!(
+                "{}",
+                serde_json::to_string(record).expect("Records are always serializable")
+            );
+        }
+    } else {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(records).expect("Records are always serializable")
+        );
+    }
+}
+
+fn print(outcomes: &[Outcome], output: Output, summary: bool) {
+    match output {
+        Output::Json | Output::JsonLines => print_json(outcomes, output),
+        Output::Quiet if summary => (),
+        Output::Quiet => outcomes.iter().for_each(super::print_quiet),
+        Output::Text if summary => super::print_summary(outcomes),
+        Output::Text => outcomes.iter().for_each(super::print_outcome),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(std::iter::once("mistletoe").chain(args.iter().copied()))
+    }
+
+    fn selected(args: &[&str]) -> Result<Vec<(u32, u32)>, String> {
+        let cli = parse(args).unwrap();
+        let selection = match cli.command {
+            Some(Command::Run(args)) => args.selection,
+            Some(Command::List(selection)) => selection,
+            _ => Selection::default(),
+        };
+        Ok(select(&selection, cli.year)?
+            .iter()
+            .map(|s| (s.year(), s.day()))
+            .collect())
+    }
+
+    #[test]
+    fn parse_subcommands() {
+        let cli = parse(&["run", "1-5,12", "--part", "2", "--example", "--json"]).unwrap();
+        assert!(cli.json);
+        match cli.command {
+            Some(Command::Run(args)) => {
+                assert_eq!(args.part, Some(2));
+                assert!(args.example);
+                assert_eq!(args.selection.targets.len(), 1);
+            }
+            other => panic!("Unexpected command: {other:?}"),
+        }
+
+        assert!(parse(&["run", "--part", "3"]).is_err());
+        assert!(parse(&["run", "26"]).is_err());
+        assert!(parse(&["run", "--input", "x", "--example"]).is_err());
+        assert!(parse(&["new", "0"]).is_err());
+        assert!(parse(&["run", "2023:5", "2022:1-25", "5"]).is_ok());
+        assert!(parse(&["list", "--quiet", "--json"]).is_err());
+        assert!(parse(&["run", "--json", "--jsonl"]).is_err());
+        assert!(parse(&["report", "--readme", "README.md", "--media", "media"]).is_ok());
+        assert!(parse(&["run", "5", "--jsonl"]).unwrap().jsonl);
+        assert!(parse(&["run", "17", "--example", "--debug"]).is_ok());
+        assert!(parse(&["submit", "2023:5", "2"]).is_ok());
+        assert!(parse(&["submit", "5", "3"]).is_err());
+        assert!(parse(&["examples", "5", "--html", "day05.html"]).is_ok());
+        assert!(parse(&["examples", "5", "--force"]).is_ok());
+        assert!(parse(&[
+            "perf-report",
+            "2023",
+            "--baseline",
+            "abc123",
+            "--threshold",
+            "5"
+        ])
+        .is_ok());
+    }
+
+    #[test]
+    fn record_full_runs_only() {
+        let records = |args: &[&str]| match parse(args).unwrap().command {
+            Some(Command::Bench(args)) => records_history(&args),
+            other => panic!("Unexpected command: {other:?}"),
+        };
+        assert!(records(&["bench", "5"]));
+        assert!(!records(&["bench", "5", "--part", "1"]));
+        assert!(!records(&["bench", "--parallel"]));
+        assert!(!records(&["bench", "--example"]));
+        assert!(!records(&["bench", "--no-record"]));
+    }
+
+    #[test]
+    fn select_days_and_years() {
+        let days = |days: &[u32]| days.iter().map(|&d| (2023, d)).collect::<Vec<_>>();
+        assert_eq!(
+            selected(&["run", "1-3,12", "--year", "2023"]),
+            Ok(days(&[1, 2, 3, 12]))
+        );
+        assert_eq!(
+            selected(&["list", "5", "3", "--year", "2023"]),
+            Ok(days(&[3, 5]))
+        );
+        assert_eq!(selected(&["list", "2023:5", "2023:3"]), Ok(days(&[3, 5])));
+        assert_eq!(selected(&["list", "2023"]).unwrap().len(), 25);
+        assert_eq!(selected(&["list", "--year", "2023"]).unwrap().len(), 25);
+        assert!(selected(&["list", "--year", "2015"]).is_err());
+        assert!(selected(&["run", "5", "--year", "2015"]).is_err());
+        assert!(selected(&["run", "2022:5"]).is_err());
+    }
+}
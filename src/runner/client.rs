@@ -0,0 +1,257 @@
+// This module talks to the puzzle website, and keeps downloaded inputs in the data directory.
+// An input never changes once it is published, so a cached input is never downloaded again.
+// Requests are spaced out by the configured interval, to be gentle on the servers.
+use super::answer::Answer;
+use super::config::{self, Config};
+use super::submit::{self, Verdict};
+use crate::error::{Error, Result};
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const USER_AGENT: &str = concat!("mistletoe/", env!("CARGO_PKG_VERSION"));
+
+// Whether an input had to be downloaded, or was already cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+    interval: Duration,
+    // When the last request was sent, shared between threads so they all respect the interval.
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client {
+            agent,
+            base_url: config.base_url.clone(),
+            session: config.session.clone(),
+            interval: config.request_interval(),
+            last_request: Mutex::new(None),
+        }
+    }
+
+    // Make sure an input is cached at the given path, downloading it only when it is missing.
+    // An empty file counts as missing, since `new` creates one as a placeholder.
+    pub fn fetch_input(&self, year: u32, day: u32, path: &Path) -> Result<Fetched> {
+        if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.input(year, day)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Write next to the final file first, so an interrupted download never looks cached.
+        let partial = path.with_extension("part");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, path)?;
+        Ok(Fetched::Downloaded)
+    }
+
+    // Download the input of a day, without caching it.
+    pub fn input(&self, year: u32, day: u32) -> Result<String> {
+        let input = self.get(&format!("/{year}/day/{day}/input"))?;
+        if input.trim().is_empty() {
+            return Err(Error::http(format!(
+                "the input of {year} day {day} is empty"
+            )));
+        }
+        Ok(input)
+    }
+
+    // Download the description of a puzzle, which includes part 2 once part 1 is solved.
+    pub fn puzzle(&self, year: u32, day: u32) -> Result<String> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    // Send the answer of a part, and work out the verdict from the response.
+    pub fn submit(&self, year: u32, day: u32, part: u8, answer: &Answer) -> Result<Verdict> {
+        let path = format!("/{year}/day/{day}/answer");
+        let request = self.request("POST", &path)?;
+        let (level, answer) = (part.to_string(), answer.to_string());
+        let page = self.send(&path, || {
+            request
+                .send_form(&[("level", &level), ("answer", &answer)])
+                .map_err(Box::new)
+        })?;
+        Ok(submit::parse_response(&page))
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let request = self.request("GET", path)?;
+        self.send(path, || request.call().map_err(Box::new))
+    }
+
+    fn request(&self, method: &str, path: &str) -> Result<ureq::Request> {
+        Ok(self
+            .agent
+            .request(method, &format!("{}{path}", self.base_url))
+            .set("Cookie", &self.cookie()?))
+    }
+
+    // The cookie every request has to carry to be logged in.
+    fn cookie(&self) -> Result<String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={session}")),
+            None => Err(Error::Config(format!(
+                "no session token, set {} or `session` in {}",
+                config::SESSION_VAR,
+                config::path().display()
+            ))),
+        }
+    }
+
+    // Wait for the rate limit, then send a request and read the body of a successful response.
+    fn send(
+        &self,
+        path: &str,
+        call: impl FnOnce() -> std::result::Result<ureq::Response, Box<ureq::Error>>,
+    ) -> Result<String> {
+        self.wait();
+        match call() {
+            Ok(response) => Ok(response.into_string()?),
+            Err(e) => Err(Error::http(format!("{path}: {}", describe(*e)))),
+        }
+    }
+
+    // Block until the configured interval has passed since the previous request.
+    fn wait(&self) {
+        let mut last = self.last_request.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(elapsed) = last.map(|at| at.elapsed()) {
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+fn describe(e: ureq::Error) -> String {
+    match e {
+        ureq::Error::Status(404, _) => "not found, the puzzle may not be unlocked yet".to_string(),
+        ureq::Error::Status(400, _) => {
+            "bad request, the session token may have expired".to_string()
+        }
+        ureq::Error::Status(status, _) => format!("status {status}"),
+        ureq::Error::Transport(e) => e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::testserver::TestServer;
+
+    fn client(url: &str, interval: f64) -> Client {
+        Client::new(&Config {
+            session: Some("53616c74".to_string()),
+            base_url: url.to_string(),
+            request_interval: interval,
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/2023/day/5/input" => (200, "seeds: 79 14 55 13\n".to_string()),
+            _ => (404, "Not found".to_string()),
+        });
+        let client = client(&server.url, 0.0);
+        let dir = std::env::temp_dir().join(format!("mistletoe-fetch-{}", std::process::id()));
+        let path = dir.join("2023/day05.txt");
+
+        assert_eq!(
+            client.fetch_input(2023, 5, &path).unwrap(),
+            Fetched::Downloaded
+        );
+        assert_eq!(client.fetch_input(2023, 5, &path).unwrap(), Fetched::Cached);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "seeds: 79 14 55 13\n");
+
+        let error = client
+            .fetch_input(2023, 6, &dir.join("2023/day06.txt"))
+            .unwrap_err();
+        assert!(error.to_string().contains("not found"));
+        assert!(!dir.join("2023/day06.txt").exists());
+        fs::remove_dir_all(&dir).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].header("Cookie"), Some("session=53616c74"));
+        assert!(requests[0]
+            .header("User-Agent")
+            .unwrap()
+            .starts_with("mistletoe/"));
+    }
+
+    #[test]
+    fn rate_limited() {
+        let server = TestServer::start(|_| (200, "1\n".to_string()));
+        let client = client(&server.url, 0.2);
+        let start = Instant::now();
+        for day in 1..=3 {
+            client.input(2023, day).unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(400));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn requires_session() {
+        let server = TestServer::start(|_| (200, "1\n".to_string()));
+        let client = Client::new(&Config {
+            session: None,
+            base_url: server.url.clone(),
+            request_interval: 0.0,
+            ..Config::default()
+        });
+        assert!(matches!(client.input(2023, 1), Err(Error::Config(_))));
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn puzzle_and_submit() {
+        let server = TestServer::start(|request| match request.body.as_str() {
+            "level=1&answer=35" => (
+                200,
+                "<article><p>That's the right answer!</p></article>".to_string(),
+            ),
+            _ => (
+                200,
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+                    .to_string(),
+            ),
+        });
+        let client = client(&server.url, 0.0);
+        assert!(client.puzzle(2023, 5).unwrap().contains("too low"));
+        assert_eq!(
+            client.submit(2023, 5, 1, &Answer::from(35)).unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            client.submit(2023, 5, 2, &Answer::from(45)).unwrap(),
+            Verdict::TooLow
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/2023/day/5");
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].path, "/2023/day/5/answer");
+        assert_eq!(requests[1].header("Cookie"), Some("session=53616c74"));
+        assert_eq!(requests[2].body, "level=2&answer=45");
+    }
+}
@@ -0,0 +1,147 @@
+// This module reads the settings needed to talk to the puzzle website from `mistletoe.toml`.
+// The session token is a secret, so it can also come from the environment instead of a file.
+// The file also holds the time budget of a day, e.g. `timeout = 10` with `[timeouts] "2023:5" = 60`.
+use super::cancel::Timeouts;
+use super::select::Target;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// The environment variable that overrides where the config file is read from.
+pub const CONFIG_VAR: &str = "MISTLETOE_CONFIG";
+// The environment variable holding the session token, it takes precedence over the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    // The value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    // Where puzzles are downloaded from, without a trailing slash.
+    pub base_url: String,
+    // The minimum number of seconds between two requests.
+    pub request_interval: f64,
+    // How many seconds a day may take when running several days, without a limit when not set.
+    pub timeout: Option<f64>,
+    // The seconds some days may take instead, keyed by a selection such as "17" or "2023:5".
+    pub timeouts: BTreeMap<String, f64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            request_interval: 5.0,
+            timeout: None,
+            timeouts: BTreeMap::new(),
+        }
+    }
+}
+
+// Get the location of the config file, `mistletoe.toml` unless overridden by the environment.
+pub fn path() -> PathBuf {
+    std::env::var_os(CONFIG_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("mistletoe.toml"))
+}
+
+impl Config {
+    // Load the config file and apply the environment, a missing file just means the defaults.
+    pub fn load() -> io::Result<Config> {
+        let mut config = Config::from_file(path())?;
+        if let Some(session) = std::env::var(SESSION_VAR)
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+        {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+
+    // Load the settings from a given file, without looking at the environment.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Config> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Config::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(contents: &str) -> io::Result<Config> {
+        let mut config: Config = toml::from_str(contents)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.message().to_string()))?;
+        config.session = config.session.map(|s| s.trim().to_string());
+        config.base_url = config.base_url.trim_end_matches('/').to_string();
+        config.timeouts()?;
+        Ok(config)
+    }
+
+    pub fn request_interval(&self) -> Duration {
+        Duration::from_secs_f64(self.request_interval.max(0.0))
+    }
+
+    pub fn timeouts(&self) -> io::Result<Timeouts> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+        let seconds = |s: f64| {
+            Duration::try_from_secs_f64(s).map_err(|_| invalid(format!("invalid timeout {s}")))
+        };
+        let days = self
+            .timeouts
+            .iter()
+            .map(|(target, s)| {
+                let target: Target = target
+                    .parse()
+                    .map_err(|e| invalid(format!("timeouts: {e}")))?;
+                Ok((target, seconds(*s)?))
+            })
+            .collect::<io::Result<_>>()?;
+        Ok(Timeouts {
+            default: self.timeout.map(seconds).transpose()?,
+            days,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let config = Config::parse(
+            "session = \" 53616c74 \"\nbase_url = \"http://127.0.0.1:8080/\"\nrequest_interval = 0.5\n",
+        )
+        .unwrap();
+        assert_eq!(config.session.as_deref(), Some("53616c74"));
+        assert_eq!(config.base_url, "http://127.0.0.1:8080");
+        assert_eq!(config.request_interval(), Duration::from_millis(500));
+
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert!(Config::parse("sesion = \"typo\"").is_err());
+
+        let timeouts = Config::parse("timeout = 10\n[timeouts]\n\"2023:5\" = 60\n\"17\" = 0.5\n")
+            .unwrap()
+            .timeouts()
+            .unwrap();
+        assert_eq!(timeouts.for_day(2023, 5), Some(Duration::from_secs(60)));
+        assert_eq!(timeouts.for_day(2023, 17), Some(Duration::from_millis(500)));
+        assert_eq!(timeouts.for_day(2023, 1), Some(Duration::from_secs(10)));
+        // The year-qualified day wins, even though "5" sorts after "2023:5".
+        let timeouts = Config::parse("[timeouts]\n\"2023:5\" = 60\n\"5\" = 10\n")
+            .unwrap()
+            .timeouts()
+            .unwrap();
+        assert_eq!(timeouts.for_day(2023, 5), Some(Duration::from_secs(60)));
+        assert_eq!(timeouts.for_day(2022, 5), Some(Duration::from_secs(10)));
+        assert!(Config::parse("[timeouts]\n\"26\" = 1\n").is_err());
+        assert!(Config::parse("timeout = -1").is_err());
+        assert_eq!(
+            Config::from_file("missing/mistletoe.toml").unwrap(),
+            Config::default()
+        );
+    }
+}
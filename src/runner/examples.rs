@@ -0,0 +1,409 @@
+// This module pulls the examples out of puzzle descriptions, and keeps the answers the puzzles give for them.
+// Examples live in `testdata/<year>/`, next to an `examples.json` that maps every day to a list of
+// named cases. Each case has its own input file, and an expected answer for either part or both.
+// The example harness in `src/examples.rs` turns every case into its own test.
+use super::answer::Answer;
+use super::solution::Runnable;
+use crate::solutions;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{btree_map::Entry, BTreeMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Example {
+    // A name that is unique within the day, such as "example" or "larger".
+    pub name: String,
+    // The file holding the example, relative to the directory of the year.
+    pub input: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+impl Example {
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    // The parts this case checks. A case without any expected answer still runs both parts,
+    // so days that are not solved yet at least have to parse their example.
+    pub fn parts(&self) -> Vec<u8> {
+        match (&self.part1, &self.part2) {
+            (None, None) => vec![1, 2],
+            (part1, part2) => [(1, part1), (2, part2)]
+                .into_iter()
+                .filter_map(|(part, answer)| answer.as_ref().map(|_| part))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Examples {
+    path: PathBuf,
+    days: BTreeMap<u32, Vec<Example>>,
+}
+
+// Get the default location of the example metadata for a given year.
+pub fn path_for(year: u32) -> PathBuf {
+    PathBuf::from(format!("testdata/{year}/examples.json"))
+}
+
+impl Examples {
+    // Load the metadata from a file, a file that does not exist yet simply has no examples.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Examples> {
+        let path = path.as_ref().to_path_buf();
+        let days = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(Examples { path, days })
+    }
+
+    // Write the metadata back to the file it was loaded from, creating its directory if needed.
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.days)?;
+        fs::write(&self.path, json + "\n")
+    }
+
+    // Get every case of a day, in the order they were added.
+    pub fn get(&self, day: u32) -> &[Example] {
+        self.days.get(&day).map_or(&[], |cases| cases)
+    }
+
+    // Add a case to a day, replacing the case with the same name if there is one.
+    pub fn set(&mut self, day: u32, example: Example) {
+        let cases = self.days.entry(day).or_default();
+        match cases.iter_mut().find(|case| case.name == example.name) {
+            Some(case) => *case = example,
+            None => cases.push(example),
+        }
+    }
+}
+
+// The examples and the emphasised answer of one part of a puzzle description.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Part {
+    pub examples: Vec<String>,
+    pub answer: Option<Answer>,
+}
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+static CODE_BLOCK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+static EMPHASISED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap()
+});
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+// Find the parts in a puzzle description, only the first one is there until it has been solved.
+// The answer of a part is the last emphasised code in it, which is where the puzzles put the example answer.
+pub fn parse_puzzle(html: &str) -> Vec<Part> {
+    ARTICLE
+        .captures_iter(html)
+        .map(|article| {
+            let article = &article[1];
+            let examples = CODE_BLOCK
+                .captures_iter(article)
+                .map(|block| text(&block[1]))
+                .collect();
+            let answer = EMPHASISED.captures_iter(article).last().map(|c| {
+                let answer = text(c.get(1).or(c.get(2)).unwrap().as_str());
+                match answer.trim().parse::<i128>() {
+                    Ok(n) => Answer::Number(n),
+                    Err(_) => Answer::from(answer.trim()),
+                }
+            });
+            Part { examples, answer }
+        })
+        .collect()
+}
+
+// Strip the markup from a piece of HTML, and turn the entities back into characters.
+fn text(html: &str) -> String {
+    TAG.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// Turn the parts of a puzzle into the example cases of a day, alongside their inputs.
+// The first example of part 1 is checked against both answers, unless part 2 brings an example of its own.
+pub fn from_puzzle(parts: &[Part], day: u32) -> Vec<(Example, String)> {
+    let Some((first, input)) = parts
+        .first()
+        .and_then(|part| Some((part, part.examples.first()?.clone())))
+    else {
+        return Vec::new();
+    };
+
+    let mut example = Example {
+        name: "example".to_string(),
+        input: format!("day{day:02}.txt"),
+        part1: first.answer.clone(),
+        part2: None,
+    };
+    let Some(second) = parts.get(1) else {
+        return vec![(example, input)];
+    };
+    match second.examples.iter().find(|e| **e != input) {
+        Some(other) => {
+            let own = Example {
+                name: "part2".to_string(),
+                input: format!("day{day:02}-part2.txt"),
+                part1: None,
+                part2: second.answer.clone(),
+            };
+            vec![(example, input), (own, other.clone())]
+        }
+        None => {
+            example.part2 = second.answer.clone();
+            vec![(example, input)]
+        }
+    }
+}
+
+// Write the example cases of a day into a testdata directory, and record their answers in the metadata.
+// Cases whose input file or metadata entry already exists are only replaced when forced to, and
+// nothing at all is written when any of them would be.
+pub fn write(
+    root: &Path,
+    year: u32,
+    day: u32,
+    html: &str,
+    force: bool,
+) -> io::Result<Vec<PathBuf>> {
+    let cases = from_puzzle(&parse_puzzle(html), day);
+    if cases.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the puzzle has no example",
+        ));
+    }
+
+    let dir = root.join(year.to_string());
+    fs::create_dir_all(&dir)?;
+    let metadata = dir.join("examples.json");
+    let mut examples = Examples::load(&metadata)?;
+    if !force {
+        for (example, _) in &cases {
+            let path = dir.join(&example.input);
+            let existing = if path.exists() {
+                Some(path.display().to_string())
+            } else {
+                examples
+                    .get(day)
+                    .iter()
+                    .any(|case| case.name == example.name)
+                    .then(|| format!("the {} case of day {day}", example.name))
+            };
+            if let Some(existing) = existing {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("{existing} already exists, use --force to replace it"),
+                ));
+            }
+        }
+    }
+
+    let mut written = Vec::new();
+    for (example, input) in cases {
+        let path = dir.join(&example.input);
+        fs::write(&path, input)?;
+        written.push(path);
+        examples.set(day, example);
+    }
+    examples.save()?;
+    written.push(metadata);
+    Ok(written)
+}
+
+// Get every example case of every registered solution. A day without any case in the metadata
+// still gets one, reading `dayNN.txt` without expecting any answer.
+pub fn cases() -> io::Result<Vec<(&'static dyn Runnable, Example)>> {
+    let mut metadata: BTreeMap<u32, Examples> = BTreeMap::new();
+    let mut cases = Vec::new();
+    for solution in solutions::all() {
+        let (year, day) = (solution.year(), solution.day());
+        if let Entry::Vacant(e) = metadata.entry(year) {
+            e.insert(Examples::load(path_for(year))?);
+        }
+        let examples = metadata[&year].get(day);
+        if examples.is_empty() {
+            let example = Example {
+                name: "example".to_string(),
+                input: format!("day{day:02}.txt"),
+                ..Example::default()
+            };
+            cases.push((solution, example));
+        }
+        cases.extend(examples.iter().map(|example| (solution, example.clone())));
+    }
+    Ok(cases)
+}
+
+// Run a part of a solution against an example case, and compare it with the expected answer if there is one.
+pub fn check(solution: &dyn Runnable, example: &Example, part: u8) -> Result<Answer, String> {
+    let path = Path::new("testdata")
+        .join(solution.year().to_string())
+        .join(&example.input);
+    let data = crate::library::utility::files::try_read_file(&path)
+        .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    let input = solution
+        .parse(&data)
+        .map_err(|e| format!("could not parse {}: {e}", path.display()))?;
+    let actual = match part {
+        1 => solution.part1(&input),
+        _ => solution.part2(&input),
+    }
+    .map_err(|e| format!("part {part} failed on {}: {e}", path.display()))?;
+
+    match example.get(part) {
+        Some(expected) if *expected != actual => Err(format!(
+            "part {part} of {}: expected {expected}, got {actual}",
+            path.display()
+        )),
+        _ => Ok(actual),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 5: Example ---</h2>
+<p>For example:</p>
+<pre><code>seeds: 79 14
+&lt;a&gt; <em>b</em>
+</code></pre>
+<p>So, the lowest location number in this example is <code><em>35</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>123</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using the same example, the answer is <em><code>46</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn parse_parts() {
+        let parts = parse_puzzle(PUZZLE);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].examples, vec!["seeds: 79 14\n<a> b\n"]);
+        assert_eq!(parts[0].answer, Some(Answer::from(35)));
+        assert!(parts[1].examples.is_empty());
+        assert_eq!(parts[1].answer, Some(Answer::from(46)));
+
+        let cases = from_puzzle(&parts, 5);
+        assert_eq!(cases.len(), 1);
+        let (example, input) = &cases[0];
+        assert_eq!(input, "seeds: 79 14\n<a> b\n");
+        assert_eq!(
+            (example.name.as_str(), example.input.as_str()),
+            ("example", "day05.txt")
+        );
+        assert_eq!(
+            (&example.part1, &example.part2),
+            (&Some(Answer::from(35)), &Some(Answer::from(46)))
+        );
+        assert_eq!(example.parts(), vec![1, 2]);
+    }
+
+    #[test]
+    fn separate_part2_example() {
+        let html = PUZZLE.replace("Using the same example", "<pre><code>other\n</code></pre>");
+        let cases = from_puzzle(&parse_puzzle(&html), 5);
+        let names: Vec<_> = cases
+            .iter()
+            .map(|(e, _)| (e.name.as_str(), e.input.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![("example", "day05.txt"), ("part2", "day05-part2.txt")]
+        );
+        assert_eq!(cases[0].0.parts(), vec![1]);
+        assert_eq!(cases[1].0.parts(), vec![2]);
+        assert_eq!(cases[1].0.part2, Some(Answer::from(46)));
+        assert_eq!(cases[1].1, "other\n");
+        assert!(from_puzzle(&parse_puzzle("<article>No example</article>"), 5).is_empty());
+    }
+
+    #[test]
+    fn several_cases() {
+        let mut examples = Examples::default();
+        let case = |name: &str, part1: i32| Example {
+            name: name.to_string(),
+            input: format!("day05-{name}.txt"),
+            part1: Some(Answer::from(part1)),
+            part2: None,
+        };
+        examples.set(5, case("small", 1));
+        examples.set(5, case("large", 2));
+        examples.set(5, case("small", 3));
+        let parts: Vec<_> = examples
+            .get(5)
+            .iter()
+            .map(|e| (e.name.as_str(), e.part1.clone()))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                ("small", Some(Answer::from(3))),
+                ("large", Some(Answer::from(2)))
+            ]
+        );
+        assert!(examples.get(6).is_empty());
+
+        let solution = solutions::find(2023, 5).unwrap();
+        let example = Example {
+            input: "day05.txt".to_string(),
+            ..case("real", 35)
+        };
+        assert_eq!(check(solution, &example, 1), Ok(Answer::from(35)));
+        let wrong = Example {
+            part1: Some(Answer::from(36)),
+            ..example
+        };
+        assert!(check(solution, &wrong, 1)
+            .unwrap_err()
+            .contains("expected 36, got 35"));
+    }
+
+    #[test]
+    fn write_testdata() {
+        let root = std::env::temp_dir().join(format!("mistletoe-examples-{}", std::process::id()));
+        let written = write(&root, 2023, 5, PUZZLE, false).unwrap();
+        assert_eq!(
+            written,
+            vec![root.join("2023/day05.txt"), root.join("2023/examples.json")]
+        );
+        let html = PUZZLE.replace("Using the same example", "<pre><code>other\n</code></pre>");
+        let err = write(&root, 2023, 5, &html, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert!(!root.join("2023/day05-part2.txt").exists());
+        write(&root, 2023, 5, &html, true).unwrap();
+
+        let examples = Examples::load(root.join("2023/examples.json")).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            examples.get(5).iter().map(|e| e.get(2)).collect::<Vec<_>>(),
+            vec![None, Some(&Answer::from(46))]
+        );
+    }
+}
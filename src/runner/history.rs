@@ -0,0 +1,309 @@
+// This module keeps the timings of every benchmark run, so performance can be compared across commits.
+// The history lives at `benchmarks/history.jsonl`, with one JSON record per day per run, tagged
+// with the commit it was measured at. Records are only ever appended.
+use super::timing::{self, Timings};
+use super::Outcome;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// The default location of the history file.
+pub const PATH: &str = "benchmarks/history.jsonl";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub commit: String,
+    // Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: u32,
+    pub day: u32,
+    pub parse_ns: u64,
+    pub part1_ns: u64,
+    pub part2_ns: u64,
+}
+
+impl Record {
+    pub fn new(outcome: &Outcome, commit: &str, timestamp: u64) -> Self {
+        let t = outcome.timings;
+        Record {
+            commit: commit.to_string(),
+            timestamp,
+            year: outcome.year,
+            day: outcome.day,
+            parse_ns: t.parse.as_nanos() as u64,
+            part1_ns: t.part1.as_nanos() as u64,
+            part2_ns: t.part2.as_nanos() as u64,
+        }
+    }
+
+    pub fn timings(&self) -> Timings {
+        Timings {
+            parse: Duration::from_nanos(self.parse_ns),
+            part1: Duration::from_nanos(self.part1_ns),
+            part2: Duration::from_nanos(self.part2_ns),
+        }
+    }
+}
+
+// Get the commit the working tree is at, marked as dirty when it has uncommitted changes.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short=10", "HEAD"]) {
+        Some(commit)
+            if git(&["status", "--porcelain", "--untracked-files=no"])
+                .is_some_and(|status| !status.is_empty()) =>
+        {
+            format!("{commit}-dirty")
+        }
+        Some(commit) => commit,
+        None => "unknown".to_string(),
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+// Format a timestamp as a calendar date in UTC, e.g. "2023-12-05".
+pub fn date(timestamp: u64) -> String {
+    // Converts days since the epoch to a civil date, following Howard Hinnant's `civil_from_days`.
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+// Load every record from a history file, a file that does not exist yet is simply an empty history.
+pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<Record>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(Into::into))
+        .collect()
+}
+
+// Append records to a history file, creating it and its directory if needed.
+pub fn append(path: impl AsRef<Path>, records: &[Record]) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for record in records {
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+    }
+    Ok(())
+}
+
+// The median total time of a day over every run at a single commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Point {
+    pub commit: String,
+    // When the commit was first measured.
+    pub timestamp: u64,
+    pub total: Duration,
+}
+
+// How the total time of a single day developed over the recorded commits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trend {
+    pub year: u32,
+    pub day: u32,
+    // Oldest first.
+    pub points: Vec<Point>,
+    pub baseline: Option<Point>,
+}
+
+impl Trend {
+    pub fn latest(&self) -> &Point {
+        self.points.last().expect("A trend has at least one point")
+    }
+
+    // The change of the latest time compared to the baseline, in percent.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline.as_ref()?.total.as_secs_f64();
+        let latest = self.latest().total.as_secs_f64();
+        (baseline > 0.0).then(|| (latest - baseline) / baseline * 100.0)
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+// The first record of a day at a commit, and the total times of every run at that commit.
+type Runs<'a> = (&'a Record, Vec<Duration>);
+
+// Work out the trend of every day in the history. The baseline is the first commit starting with
+// the given prefix, or the commit before the latest one when no baseline is given.
+pub fn trends(records: &[Record], baseline: Option<&str>) -> Vec<Trend> {
+    let mut days: BTreeMap<(u32, u32), Vec<Runs>> = BTreeMap::new();
+    for record in records {
+        let commits = days.entry((record.year, record.day)).or_default();
+        let total = record.timings().total();
+        match commits
+            .iter_mut()
+            .find(|(first, _)| first.commit == record.commit)
+        {
+            Some((_, samples)) => samples.push(total),
+            None => commits.push((record, vec![total])),
+        }
+    }
+
+    days.into_iter()
+        .map(|((year, day), commits)| {
+            let points: Vec<_> = commits
+                .into_iter()
+                .map(|(first, mut samples)| Point {
+                    commit: first.commit.clone(),
+                    timestamp: first.timestamp,
+                    total: timing::median(&mut samples),
+                })
+                .collect();
+            let baseline = match baseline {
+                Some(prefix) => points.iter().find(|p| p.commit.starts_with(prefix)),
+                None => points.len().checked_sub(2).map(|i| &points[i]),
+            }
+            .cloned();
+            Trend {
+                year,
+                day,
+                points,
+                baseline,
+            }
+        })
+        .collect()
+}
+
+// Print a line per day with its recent trend and the change against the baseline.
+// Returns false if any day slowed down by more than the threshold, in percent.
+pub fn print_report(trends: &[Trend], threshold: f64) -> bool {
+    const RECENT: usize = 5;
+    let mut ok = true;
+    for trend in trends {
+        let recent: Vec<_> = trend.points[trend.points.len().saturating_sub(RECENT)..]
+            .iter()
+            .map(|point| timing::format(point.total))
+            .collect();
+        let change = match (trend.change(), &trend.baseline) {
+            (Some(change), Some(baseline)) => format!(
+                "{change:+.1}% vs {} from {}",
+                baseline.commit,
+                date(baseline.timestamp)
+            ),
+            _ => "no baseline".to_string(),
+        };
+        let flag = if trend.regressed(threshold) {
+            ok = false;
+            "  REGRESSION"
+        } else {
+            ""
+        };
+        println!(
+            "{} Day {:02}: {} ({change}){flag}",
+            trend.year,
+            trend.day,
+            recent.join(" -> ")
+        );
+    }
+    ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, day: u32, ms: u64) -> Record {
+        Record {
+            commit: commit.to_string(),
+            timestamp: 1_701_734_400,
+            year: 2023,
+            day,
+            parse_ns: 0,
+            part1_ns: ms * 1_000_000,
+            part2_ns: 0,
+        }
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(1_701_734_400), "2023-12-05");
+        assert_eq!(date(951_782_400), "2000-02-29");
+    }
+
+    #[test]
+    fn flag_regressions() {
+        let ms = Duration::from_millis;
+        let records = vec![
+            record("aaa", 5, 10),
+            record("aaa", 5, 12),
+            record("aaa", 5, 14),
+            record("aaa", 16, 100),
+            record("bbb", 5, 11),
+            record("bbb", 16, 40),
+            record("ccc", 5, 20),
+        ];
+
+        let trends = trends(&records, None);
+        assert_eq!(trends.len(), 2);
+        let points: Vec<_> = trends[0]
+            .points
+            .iter()
+            .map(|p| (p.commit.as_str(), p.total))
+            .collect();
+        assert_eq!(
+            points,
+            vec![("aaa", ms(12)), ("bbb", ms(11)), ("ccc", ms(20))]
+        );
+        assert_eq!(trends[0].baseline.as_ref().map(|p| p.total), Some(ms(11)));
+        assert!(trends[0].regressed(10.0));
+        assert!(!trends[0].regressed(100.0));
+        assert!(trends[1]
+            .change()
+            .is_some_and(|change| (change + 60.0).abs() < 1e-9));
+        assert!(!trends[1].regressed(10.0));
+
+        let against_first = super::trends(&records, Some("aa"));
+        let baseline = against_first[1].baseline.as_ref().unwrap();
+        assert_eq!((baseline.commit.as_str(), baseline.total), ("aaa", ms(100)));
+        assert!(super::trends(&records, Some("zzz"))[0].change().is_none());
+    }
+
+    #[test]
+    fn round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("mistletoe-history-{}.jsonl", std::process::id()));
+        let records = vec![record("aaa", 5, 10), record("bbb", 5, 11)];
+        append(&path, &records[..1]).unwrap();
+        append(&path, &records[1..]).unwrap();
+        let loaded = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, records);
+    }
+}
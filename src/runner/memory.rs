@@ -0,0 +1,149 @@
+// This module counts heap allocations, to tell which days are memory-bound.
+// With the `alloc-stats` feature the library installs a counting global allocator, and the runner
+// reports the allocations, bytes allocated and peak live bytes of parse, part 1 and part 2.
+// Without the feature nothing is counted, and the runner reports no memory at all.
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Wraps the system allocator, keeping a running count of everything that goes through it.
+pub struct Counter;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+impl Counter {
+    fn grow(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        LIVE.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Counter::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Counter::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Counter::shrink(layout.size());
+    }
+
+    // A reallocation counts as a single allocation of the new size, replacing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            Counter::grow(new_size);
+            Counter::shrink(layout.size());
+        }
+        new
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+// Whether allocations are being counted at all.
+pub const fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+// The allocations of a single step.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Usage {
+    pub allocations: u64,
+    pub bytes: u64,
+    // The most bytes live at once on top of what was live before the step started.
+    pub peak_bytes: u64,
+}
+
+// The allocations of every step of a day, only present when the counting allocator is installed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Memory {
+    pub parse: Usage,
+    pub part1: Usage,
+    pub part2: Usage,
+}
+
+// Run the given function, counting what it allocates. The counters are shared by every thread,
+// so anything allocating at the same time on another thread is counted as well.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let value = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (value, usage)
+}
+
+// Format an amount of bytes with a binary unit, e.g. "1.50 MiB".
+pub fn format(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.2} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bytes() {
+        assert_eq!(format(0), "0 B");
+        assert_eq!(format(1023), "1023 B");
+        assert_eq!(format(1536), "1.50 KiB");
+        assert_eq!(format(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    fn count_allocations() {
+        let (sum, usage) = measure(|| {
+            let numbers: Vec<u64> = (0..1000).collect();
+            numbers.iter().sum::<u64>()
+        });
+        assert_eq!(sum, 499_500);
+        if enabled() {
+            // Other tests allocate and free concurrently, so only the totals have a certain lower bound.
+            assert!(usage.allocations >= 1);
+            assert!(usage.bytes >= 8000);
+        } else {
+            assert_eq!(usage, Usage::default());
+        }
+    }
+}
@@ -0,0 +1,393 @@
+// This module writes a progress report from local data only: the accepted answers and the timing history.
+// It renders a Markdown table with the stars and timings of every day, an SVG badge per day, and
+// puts both into the README between the AOC TILES markers.
+use super::history::{self, Record};
+use super::solution::Runnable;
+use super::store::{self, AnswerStore};
+use super::timing::{self, Timings};
+use std::collections::{btree_map::Entry, BTreeMap};
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const BEGIN: &str = "<!-- AOC TILES BEGIN -->";
+pub const END: &str = "<!-- AOC TILES END -->";
+
+// Everything the report shows about a single day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub year: u32,
+    pub day: u32,
+    pub name: &'static str,
+    pub stars: usize,
+    // The median timings at the latest benchmarked commit, if the day was benchmarked at all.
+    pub timings: Option<Timings>,
+}
+
+impl DayReport {
+    // Where the badge of the day lives, relative to the media directory.
+    pub fn badge_path(&self) -> PathBuf {
+        PathBuf::from(self.year.to_string()).join(format!("day{:02}.svg", self.day))
+    }
+
+    pub fn source_path(&self) -> String {
+        format!("src/solutions/y{}/day{:02}.rs", self.year, self.day)
+    }
+}
+
+// Get the median timings of every day at the commit it was last benchmarked at.
+pub fn latest_timings(records: &[Record]) -> BTreeMap<(u32, u32), Timings> {
+    let mut latest: BTreeMap<(u32, u32), Vec<&Record>> = BTreeMap::new();
+    for record in records {
+        let runs = latest.entry((record.year, record.day)).or_default();
+        if runs.first().is_some_and(|r| r.commit != record.commit) {
+            runs.clear();
+        }
+        runs.push(record);
+    }
+
+    latest
+        .into_iter()
+        .map(|(key, runs)| {
+            let median = |step: fn(&Timings) -> Duration| {
+                timing::median(&mut runs.iter().map(|r| step(&r.timings())).collect::<Vec<_>>())
+            };
+            let timings = Timings {
+                parse: median(|t| t.parse),
+                part1: median(|t| t.part1),
+                part2: median(|t| t.part2),
+            };
+            (key, timings)
+        })
+        .collect()
+}
+
+// Gather the stars and timings of every given solution.
+pub fn collect<'a>(
+    solutions: impl IntoIterator<Item = &'a dyn Runnable>,
+    stores: &BTreeMap<u32, AnswerStore>,
+    timings: &BTreeMap<(u32, u32), Timings>,
+) -> Vec<DayReport> {
+    solutions
+        .into_iter()
+        .map(|solution| {
+            let (year, day) = (solution.year(), solution.day());
+            let stars = stores.get(&year).map_or(0, |store| {
+                [1, 2]
+                    .into_iter()
+                    .filter(|&part| store.get(day, part).is_some())
+                    .count()
+            });
+            DayReport {
+                year,
+                day,
+                name: solution.name(),
+                stars,
+                timings: timings.get(&(year, day)).copied(),
+            }
+        })
+        .collect()
+}
+
+// The lines of the report for a single year, kept per day so that a report of only some days can
+// be merged into an existing one.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct YearReport {
+    pub tiles: BTreeMap<u32, String>,
+    pub rows: BTreeMap<u32, String>,
+}
+
+// Render the tile and the table row of every given day, grouped by year.
+pub fn year_reports(days: &[DayReport], media: &str) -> BTreeMap<u32, YearReport> {
+    let mut years: BTreeMap<u32, YearReport> = BTreeMap::new();
+    for day in days {
+        let report = years.entry(day.year).or_default();
+        let tile = format!(
+            "<a href=\"{}\"><img src=\"{media}/{}\" alt=\"Day {:02}\"></a>",
+            day.source_path(),
+            day.badge_path().display(),
+            day.day
+        );
+        report.tiles.insert(day.day, tile);
+
+        let time = |step: fn(&Timings) -> Duration| {
+            day.timings
+                .as_ref()
+                .map_or("-".to_string(), |t| timing::format(step(t)))
+        };
+        let row = format!(
+            "| {} | [{}]({}) | {} | {} | {} | {} |",
+            day.day,
+            day.name,
+            day.source_path(),
+            "⭐".repeat(day.stars),
+            time(|t| t.parse),
+            time(|t| t.part1),
+            time(|t| t.part2)
+        );
+        report.rows.insert(day.day, row);
+    }
+    years
+}
+
+// Read the tiles and table rows of every day back from a rendered report, anything else is left out.
+pub fn parse_year_reports(section: &str) -> BTreeMap<u32, YearReport> {
+    let mut years: BTreeMap<u32, YearReport> = BTreeMap::new();
+    let mut year = None;
+    for line in section.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            year = heading
+                .split_whitespace()
+                .next()
+                .and_then(|y| y.parse().ok());
+            continue;
+        }
+        let Some(year) = year else {
+            continue;
+        };
+
+        let report = years.entry(year).or_default();
+        if let Some((_, alt)) = line.split_once("alt=\"Day ") {
+            if let Some(day) = alt.get(..2).and_then(|d| d.parse().ok()) {
+                report.tiles.insert(day, line.to_string());
+            }
+        } else if let Some(day) = line
+            .strip_prefix("| ")
+            .and_then(|row| row.split(" |").next())
+            .and_then(|d| d.parse().ok())
+        {
+            report.rows.insert(day, line.to_string());
+        }
+    }
+    years
+}
+
+// Render the tiles and the table of every day, with a section per year.
+pub fn render(years: &BTreeMap<u32, YearReport>) -> String {
+    let mut out = String::new();
+    for (year, report) in years {
+        let stars: usize = report
+            .rows
+            .values()
+            .map(|row| row.matches('⭐').count())
+            .sum();
+        let _ = writeln!(out, "## {year} ({stars} ⭐)\n");
+        for tile in report.tiles.values() {
+            let _ = writeln!(out, "{tile}");
+        }
+        let _ = writeln!(out, "\n| Day | Puzzle | Stars | Parse | Part 1 | Part 2 |");
+        let _ = writeln!(out, "|----:|--------|-------|------:|-------:|-------:|");
+        for row in report.rows.values() {
+            let _ = writeln!(out, "{row}");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// Render the table of every given day, with a section per year.
+pub fn markdown(days: &[DayReport], media: &str) -> String {
+    render(&year_reports(days, media))
+}
+
+// Render the given days into an existing report. Days that are not given keep what the report
+// already shows for them, so reporting a single year or day leaves the others in place.
+pub fn merge(existing: &str, days: &[DayReport], media: &str) -> String {
+    let mut years = parse_year_reports(existing);
+    for (year, report) in year_reports(days, media) {
+        let merged = years.entry(year).or_default();
+        merged.tiles.extend(report.tiles);
+        merged.rows.extend(report.rows);
+    }
+    render(&years)
+}
+
+// Render the badge of a day, showing its stars and total time.
+pub fn badge(day: &DayReport) -> String {
+    let label = format!("Day {:02}", day.day);
+    let mut value = "★".repeat(day.stars) + &"☆".repeat(2 - day.stars.min(2));
+    if let Some(timings) = &day.timings {
+        value = format!("{value} {}", timing::format(timings.total()));
+    }
+    let colour = match day.stars {
+        0 => "#9f9f9f",
+        1 => "#dfb317",
+        _ => "#4c1",
+    };
+
+    // Roughly the width of the text in the badge font, with some padding on either side.
+    let width = |text: &str| text.chars().count() * 7 + 12;
+    let (left, right) = (width(&label), width(&value));
+    let total = left + right;
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total}" height="20" role="img" aria-label="{label}: {value}">
+  <title>{year} {label}: {name}</title>
+  <rect width="{left}" height="20" fill="#0f0f23"/>
+  <rect x="{left}" width="{right}" height="20" fill="{colour}"/>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_x}" y="14">{label}</text>
+    <text x="{value_x}" y="14">{value}</text>
+  </g>
+</svg>
+"##,
+        year = day.year,
+        name = escape(day.name),
+        label_x = left / 2,
+        value_x = left + right / 2,
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Find where the section between the markers of a README starts and ends.
+fn bounds(readme: &str) -> Option<(usize, usize)> {
+    let start = readme.find(BEGIN)? + BEGIN.len();
+    let end = start + readme[start..].find(END)?;
+    Some((start, end))
+}
+
+// Replace everything between the markers of a README with a new section, None if the markers are missing.
+pub fn update_readme(readme: &str, section: &str) -> Option<String> {
+    let (start, end) = bounds(readme)?;
+    Some(format!(
+        "{}\n{}{}",
+        &readme[..start],
+        section,
+        &readme[end..]
+    ))
+}
+
+// Write the badges of every given solution into the media directory, and the report into the README.
+pub fn write<'a>(
+    solutions: impl IntoIterator<Item = &'a dyn Runnable>,
+    readme: &Path,
+    media: &Path,
+) -> io::Result<Vec<PathBuf>> {
+    let solutions: Vec<_> = solutions.into_iter().collect();
+    let mut stores = BTreeMap::new();
+    for solution in &solutions {
+        let year = solution.year();
+        if let Entry::Vacant(e) = stores.entry(year) {
+            e.insert(AnswerStore::load(store::path_for(year))?);
+        }
+    }
+    let timings = latest_timings(&history::load(history::PATH)?);
+    let days = collect(solutions, &stores, &timings);
+
+    let mut written = Vec::new();
+    for day in &days {
+        let path = media.join(day.badge_path());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, badge(day))?;
+        written.push(path);
+    }
+
+    let contents = fs::read_to_string(readme)?;
+    let missing = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} has no {BEGIN} and {END} markers", readme.display()),
+        )
+    };
+    let (start, end) = bounds(&contents).ok_or_else(missing)?;
+    let section = format!(
+        "<h1 align=\"center\">\n  ❄️ Mistletoe ❄️\n</h1>\n\n{}",
+        merge(&contents[start..end], &days, &media.display().to_string())
+    );
+    let updated = update_readme(&contents, &section).ok_or_else(missing)?;
+    fs::write(readme, updated)?;
+    written.push(readme.to_path_buf());
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::answer::Answer;
+    use crate::solutions;
+
+    fn record(commit: &str, day: u32, parse_ns: u64) -> Record {
+        Record {
+            commit: commit.to_string(),
+            timestamp: 0,
+            year: 2023,
+            day,
+            parse_ns,
+            part1_ns: 1_000,
+            part2_ns: 2_000,
+        }
+    }
+
+    #[test]
+    fn report_days() {
+        let records = vec![
+            record("aaa", 5, 100),
+            record("bbb", 5, 10),
+            record("bbb", 5, 30),
+            record("bbb", 5, 20),
+            record("aaa", 6, 7),
+        ];
+        let timings = latest_timings(&records);
+        assert_eq!(timings[&(2023, 5)].parse, Duration::from_nanos(20));
+        assert_eq!(timings[&(2023, 6)].parse, Duration::from_nanos(7));
+
+        let mut store = AnswerStore::default();
+        store.accept(5, 1, Answer::from(35));
+        store.accept(5, 2, Answer::from(46));
+        store.accept(6, 1, Answer::from(288));
+        let stores = BTreeMap::from([(2023, store)]);
+        let selected = solutions::filter(|s| s.year() == 2023 && (5..=7).contains(&s.day()));
+        let days = collect(selected, &stores, &timings);
+        let stars: Vec<_> = days
+            .iter()
+            .map(|d| (d.day, d.stars, d.timings.is_some()))
+            .collect();
+        assert_eq!(stars, vec![(5, 2, true), (6, 1, true), (7, 0, false)]);
+
+        let table = markdown(&days, "media");
+        assert!(table.starts_with("## 2023 (3 ⭐)\n"));
+        assert!(table.contains("<a href=\"src/solutions/y2023/day05.rs\"><img src=\"media/2023/day05.svg\" alt=\"Day 05\"></a>"));
+        assert!(table.contains("| 5 | [If You Give A Seed A Fertilizer](src/solutions/y2023/day05.rs) | ⭐⭐ | 20.00ns | 1.00µs | 2.00µs |"));
+        assert!(
+            table.contains("| 7 | [Camel Cards](src/solutions/y2023/day07.rs) |  | - | - | - |")
+        );
+
+        let svg = badge(&days[1]);
+        assert!(svg.starts_with("<svg") && svg.contains("#dfb317"));
+        assert!(svg.contains(">★☆ 3.01µs</text>"));
+
+        // Reporting a single day keeps the other days and years that are already in the report.
+        assert_eq!(parse_year_reports(&table), year_reports(&days, "media"));
+        let existing =
+            format!("## 2022 (1 ⭐)\n\n| 1 | [Old](old.rs) | ⭐ | - | - | - |\n\n{table}");
+        let mut day = days[2].clone();
+        day.stars = 2;
+        let merged = merge(&existing, &[day], "media");
+        assert!(merged.starts_with("## 2022 (1 ⭐)\n"));
+        assert!(merged.contains("| 1 | [Old](old.rs) | ⭐ | - | - | - |"));
+        assert!(merged.contains("## 2023 (5 ⭐)\n"));
+        assert!(merged.contains(
+            "| 5 | [If You Give A Seed A Fertilizer](src/solutions/y2023/day05.rs) | ⭐⭐ |"
+        ));
+        assert!(merged.contains("| 7 | [Camel Cards](src/solutions/y2023/day07.rs) | ⭐⭐ |"));
+    }
+
+    #[test]
+    fn replace_between_markers() {
+        let readme = format!("# Title\n{BEGIN}\nold tiles\n{END}\nFooter\n");
+        assert_eq!(
+            update_readme(&readme, "new\n").unwrap(),
+            format!("# Title\n{BEGIN}\nnew\n{END}\nFooter\n")
+        );
+        assert!(update_readme("no markers", "new\n").is_none());
+    }
+}
@@ -0,0 +1,212 @@
+// This module creates a new day from the template in `src/template.rs`, and registers it with its year.
+// In the template `XX` is replaced by the zero-padded day, `DAY` by the plain number and `YEAR` by the year.
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../template.rs");
+
+// Fill in the template for the given day.
+pub fn render(year: u32, day: u32) -> String {
+    TEMPLATE
+        .replace("XX", &format!("{day:02}"))
+        .replace("DAY", &day.to_string())
+        .replace("YEAR", &year.to_string())
+}
+
+// The module of a year that has no days registered yet.
+fn year_module(year: u32) -> String {
+    format!(
+        "// The solutions of Advent of Code {year}.\n\
+         use crate::runner::solution::Runnable;\n\
+         \n\
+         register! {{\n\
+         }}\n"
+    )
+}
+
+// Add a day to the `register!` block of a year module, keeping the days sorted.
+// Returns None if the block can not be found, and the source unchanged if the day is already registered.
+pub fn register(source: &str, day: u32) -> Option<String> {
+    let entry = format!("day{day:02}::Day{day:02},");
+    let start = source.find("register! {\n")? + "register! {\n".len();
+    let end = start + source[start..].find("}\n")?;
+
+    let mut entries: Vec<&str> = source[start..end].lines().map(str::trim).collect();
+    if !entries.contains(&entry.as_str()) {
+        entries.push(&entry);
+        entries.sort_unstable();
+    }
+
+    let block: String = entries.iter().map(|e| format!("    {e}\n")).collect();
+    Some(format!("{}{block}{}", &source[..start], &source[end..]))
+}
+
+// Add a year to `src/solutions.rs`, declaring its module and adding its registry to the list of years.
+// Returns None if the list can not be found, and the source unchanged if the year is already registered.
+pub fn register_year(source: &str, year: u32) -> Option<String> {
+    let module = format!("pub mod y{year};");
+    let start = source.find("pub mod y")?;
+    let end = start + source[start..].find("\n\n")?;
+    let mut modules: Vec<&str> = source[start..end].lines().collect();
+    if !modules.contains(&module.as_str()) {
+        modules.push(&module);
+        modules.sort_unstable();
+    }
+    let source = format!(
+        "{}{}{}",
+        &source[..start],
+        modules.join("\n"),
+        &source[end..]
+    );
+
+    let open = "static YEARS: &[&[&dyn Runnable]] = &[";
+    let start = source.find(open)? + open.len();
+    let end = start + source[start..].find("];")?;
+    let entry = format!("y{year}::SOLUTIONS");
+    let mut years: Vec<&str> = source[start..end].split(',').map(str::trim).collect();
+    years.retain(|y| !y.is_empty());
+    if !years.contains(&entry.as_str()) {
+        years.push(&entry);
+        years.sort_unstable();
+    }
+    Some(format!(
+        "{}{}{}",
+        &source[..start],
+        years.join(", "),
+        &source[end..]
+    ))
+}
+
+// Create an empty file unless it already exists, so an input that is already there is kept.
+fn touch(path: &Path) -> io::Result<bool> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+// Read a source file, apply an edit and write it back only if anything changed.
+fn update(path: &Path, edit: impl Fn(&str) -> Option<String>) -> io::Result<bool> {
+    let source = fs::read_to_string(path)?;
+    let updated = edit(&source).ok_or_else(|| {
+        let message = format!("could not find where to register in {}", path.display());
+        io::Error::new(io::ErrorKind::InvalidData, message)
+    })?;
+    if updated == source {
+        return Ok(false);
+    }
+    fs::write(path, updated)?;
+    Ok(true)
+}
+
+// Create a new day below the given project root, refusing to overwrite an existing solution.
+// This writes the solution from the template, adds empty input files and registers the day,
+// along with its year if it is the first day of that year.
+// Returns every file that was created or changed.
+pub fn create(root: &Path, data: &Path, year: u32, day: u32) -> io::Result<Vec<PathBuf>> {
+    let path = root.join(format!("src/solutions/y{year}/day{day:02}.rs"));
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Opening with create_new fails if the file already exists, so nothing is ever overwritten.
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::AlreadyExists => {
+                io::Error::new(e.kind(), format!("{} already exists", path.display()))
+            }
+            _ => e,
+        })?;
+    file.write_all(render(year, day).as_bytes())?;
+    let mut changed = vec![path];
+
+    for input in [root.join(data), root.join("testdata")] {
+        let input = input.join(format!("{year}/day{day:02}.txt"));
+        if touch(&input)? {
+            changed.push(input);
+        }
+    }
+
+    let module = root.join(format!("src/solutions/y{year}.rs"));
+    if !module.exists() {
+        fs::write(&module, year_module(year))?;
+    }
+    if update(&module, |source| register(source, day))? {
+        changed.push(module);
+    }
+
+    let registry = root.join("src/solutions.rs");
+    if update(&registry, |source| register_year(source, year))? {
+        changed.push(registry);
+    }
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_fills_placeholders() {
+        let source = render(2022, 7);
+        assert!(source.starts_with("// https://adventofcode.com/2022/day/07\n"));
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("fn day(&self) -> u32 {\n        7\n    }"));
+        assert!(source.contains("fn year(&self) -> u32 {\n        2022\n    }"));
+        assert!(!source.contains("XX") && !source.contains("DAY") && !source.contains("YEAR"));
+    }
+
+    #[test]
+    fn register_keeps_days_sorted() {
+        let source = "register! {\n    day01::Day01,\n    day12::Day12,\n}\n\nfn all() {}\n";
+        let expected = "register! {\n    day01::Day01,\n    day03::Day03,\n    day12::Day12,\n}\n\nfn all() {}\n";
+        assert_eq!(register(source, 3).unwrap(), expected);
+        assert_eq!(register(expected, 3).unwrap(), expected);
+        assert_eq!(register("fn all() {}\n", 3), None);
+    }
+
+    #[test]
+    fn register_years() {
+        let source = "pub mod y2023;\n\nstatic YEARS: &[&[&dyn Runnable]] = &[y2023::SOLUTIONS];\n";
+        let expected = "pub mod y2022;\npub mod y2023;\n\n\
+                        static YEARS: &[&[&dyn Runnable]] = &[y2022::SOLUTIONS, y2023::SOLUTIONS];\n";
+        assert_eq!(register_year(source, 2022).unwrap(), expected);
+        assert_eq!(register_year(expected, 2023).unwrap(), expected);
+    }
+
+    #[test]
+    fn create_refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("mistletoe-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        let registry =
+            "pub mod y2023;\n\nstatic YEARS: &[&[&dyn Runnable]] = &[y2023::SOLUTIONS];\n";
+        fs::write(root.join("src/solutions.rs"), registry).unwrap();
+
+        let changed = create(&root, Path::new("data"), 2022, 3).unwrap();
+        assert_eq!(changed.len(), 5);
+        assert_eq!(fs::read_to_string(&changed[0]).unwrap(), render(2022, 3));
+        assert_eq!(
+            fs::read_to_string(root.join("data/2022/day03.txt")).unwrap(),
+            ""
+        );
+        assert!(fs::read_to_string(root.join("src/solutions/y2022.rs"))
+            .unwrap()
+            .contains("register! {\n    day03::Day03,\n}\n"));
+        assert!(fs::read_to_string(root.join("src/solutions.rs"))
+            .unwrap()
+            .contains("pub mod y2022;"));
+
+        let err = create(&root, Path::new("data"), 2022, 3).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,184 @@
+// This module parses day selections such as "5", "1-5" or "1-5,12" into a sorted set of days.
+// A selection can be restricted to a single year by prefixing it, e.g. "2022:1-25" or just "2022" for the whole year.
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+pub const FIRST_DAY: u32 = 1;
+pub const LAST_DAY: u32 = 25;
+pub const FIRST_YEAR: u32 = 2015;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Days(BTreeSet<u32>);
+
+// A selection of days, optionally within a single year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    pub year: Option<u32>,
+    // Every registered day is selected when this is not set.
+    pub days: Option<Days>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectError(String);
+
+impl fmt::Display for SelectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for SelectError {}
+
+impl Days {
+    pub fn all() -> Days {
+        Days((FIRST_DAY..=LAST_DAY).collect())
+    }
+
+    pub fn contains(&self, day: u32) -> bool {
+        self.0.contains(&day)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+fn parse_day(s: &str) -> Result<u32, SelectError> {
+    let day = s
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| SelectError(format!("invalid day '{s}', expected a number")))?;
+    if (FIRST_DAY..=LAST_DAY).contains(&day) {
+        Ok(day)
+    } else {
+        Err(SelectError(format!(
+            "invalid day '{s}', days range from {FIRST_DAY} to {LAST_DAY}"
+        )))
+    }
+}
+
+fn parse_year(s: &str) -> Result<u32, SelectError> {
+    let year = s
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| SelectError(format!("invalid year '{s}', expected a number")))?;
+    if year >= FIRST_YEAR {
+        Ok(year)
+    } else {
+        Err(SelectError(format!(
+            "invalid year '{s}', Advent of Code started in {FIRST_YEAR}"
+        )))
+    }
+}
+
+impl Target {
+    // Select every registered day of every year.
+    pub fn all() -> Target {
+        Target {
+            year: None,
+            days: None,
+        }
+    }
+
+    pub fn matches(&self, year: u32, day: u32) -> bool {
+        self.year.is_none_or(|y| y == year) && self.days.as_ref().is_none_or(|d| d.contains(day))
+    }
+}
+
+impl FromStr for Target {
+    type Err = SelectError;
+
+    // Parse "YEAR:DAYS", a bare year, or bare days which match every year.
+    fn from_str(s: &str) -> Result<Target, SelectError> {
+        let s = s.trim();
+        let (year, days) = match s.split_once(':') {
+            Some((year, days)) => (Some(parse_year(year)?), days),
+            None if s.len() == 4 && s.chars().all(|c| c.is_ascii_digit()) => {
+                (Some(parse_year(s)?), "*")
+            }
+            None => (None, s),
+        };
+
+        let days = match days.trim() {
+            "*" => None,
+            days => Some(days.parse()?),
+        };
+        Ok(Target { year, days })
+    }
+}
+
+impl FromStr for Days {
+    type Err = SelectError;
+
+    // Parse a comma separated list of days and inclusive ranges, "*" selects every day.
+    fn from_str(s: &str) -> Result<Days, SelectError> {
+        if s.trim() == "*" {
+            return Ok(Days::all());
+        }
+
+        let mut days = BTreeSet::new();
+        for item in s.split(',') {
+            match item.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse_day(start)?, parse_day(end)?);
+                    if start > end {
+                        return Err(SelectError(format!(
+                            "invalid range '{item}', the start is after the end"
+                        )));
+                    }
+                    days.extend(start..=end);
+                }
+                None => {
+                    days.insert(parse_day(item)?);
+                }
+            }
+        }
+        Ok(Days(days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(s: &str) -> Vec<u32> {
+        s.parse::<Days>().unwrap().iter().collect()
+    }
+
+    #[test]
+    fn parse_lists_and_ranges() {
+        assert_eq!(days("5"), vec![5]);
+        assert_eq!(days("05"), vec![5]);
+        assert_eq!(days("1-5,12"), vec![1, 2, 3, 4, 5, 12]);
+        assert_eq!(days("12,3,3"), vec![3, 12]);
+        assert_eq!(days("*"), (1..=25).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn parse_targets() {
+        let target = "2022:1-3".parse::<Target>().unwrap();
+        assert_eq!(target.year, Some(2022));
+        assert!(target.matches(2022, 2) && !target.matches(2022, 4) && !target.matches(2023, 2));
+
+        let target = "2023".parse::<Target>().unwrap();
+        assert_eq!((target.year, target.days), (Some(2023), None));
+
+        let target = "5".parse::<Target>().unwrap();
+        assert!(target.matches(2022, 5) && target.matches(2023, 5));
+        assert_eq!("*".parse::<Target>().unwrap(), Target::all());
+
+        assert!("2014:5".parse::<Target>().is_err());
+        assert!("2023:26".parse::<Target>().is_err());
+        assert!("x:5".parse::<Target>().is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!("0".parse::<Days>().is_err());
+        assert!("26".parse::<Days>().is_err());
+        assert!("5-1".parse::<Days>().is_err());
+        assert!("a".parse::<Days>().is_err());
+        assert!("1,".parse::<Days>().is_err());
+    }
+}
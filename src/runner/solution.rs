@@ -0,0 +1,83 @@
+// This module contains the trait every day implements, and a type-erased version of it.
+// The erased version is what the registry stores, so the runner can treat all days the same way.
+use super::answer::Answer;
+use crate::error::Result;
+use std::any::Any;
+
+pub trait Solution: Sync {
+    // The parsed representation of the puzzle input, shared by both parts.
+    type Input: Send + Sync + 'static;
+
+    // Parsing fails on malformed input, solving fails when the input has no answer.
+    fn parse(&self, data: &[String]) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    // A drawing of how the answers were found, e.g. the route taken through a grid, shown when
+    // running in debug mode. Most days have nothing to show.
+    fn debug(&self, _input: &Self::Input) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    // The title of the puzzle, as shown on the Advent of Code website.
+    fn name(&self) -> &'static str;
+    fn day(&self) -> u32;
+    fn year(&self) -> u32;
+}
+
+// A parsed input whose concrete type is only known to the solution that produced it.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+// Object-safe counterpart of `Solution`, implemented for every solution automatically.
+pub trait Runnable: Sync {
+    fn parse(&self, data: &[String]) -> Result<Parsed>;
+    fn part1(&self, input: &Parsed) -> Result<Answer>;
+    fn part2(&self, input: &Parsed) -> Result<Answer>;
+    fn debug(&self, input: &Parsed) -> Result<Option<String>>;
+
+    fn name(&self) -> &'static str;
+    fn day(&self) -> u32;
+    fn year(&self) -> u32;
+
+    // Describe the solution in a single line, e.g. "2023 Day 05: If You Give A Seed A Fertilizer".
+    fn describe(&self) -> String {
+        format!("{} Day {:02}: {}", self.year(), self.day(), self.name())
+    }
+}
+
+impl<S: Solution> Runnable for S {
+    fn parse(&self, data: &[String]) -> Result<Parsed> {
+        Ok(Box::new(Solution::parse(self, data)?))
+    }
+
+    fn part1(&self, input: &Parsed) -> Result<Answer> {
+        Solution::part1(self, downcast::<S>(input))
+    }
+
+    fn part2(&self, input: &Parsed) -> Result<Answer> {
+        Solution::part2(self, downcast::<S>(input))
+    }
+
+    fn debug(&self, input: &Parsed) -> Result<Option<String>> {
+        Solution::debug(self, downcast::<S>(input))
+    }
+
+    fn name(&self) -> &'static str {
+        Solution::name(self)
+    }
+
+    fn day(&self) -> u32 {
+        Solution::day(self)
+    }
+
+    fn year(&self) -> u32 {
+        Solution::year(self)
+    }
+}
+
+// Recover the concrete input of a solution, this can only fail if inputs get mixed up between days.
+fn downcast<S: Solution>(input: &Parsed) -> &S::Input {
+    input
+        .downcast_ref::<S::Input>()
+        .expect("Parsed input does not belong to this solution")
+}
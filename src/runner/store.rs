@@ -0,0 +1,100 @@
+// This module keeps the accepted answers for every day of a year in a local JSON file.
+// The file lives at `answers/<year>.json`, and maps each day to the accepted answer of both parts.
+use super::answer::Answer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Accepted {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+impl Accepted {
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AnswerStore {
+    path: PathBuf,
+    days: BTreeMap<u32, Accepted>,
+}
+
+// Get the default location of the answer file for a given year.
+pub fn path_for(year: u32) -> PathBuf {
+    PathBuf::from(format!("answers/{year}.json"))
+}
+
+impl AnswerStore {
+    // Load the answers from a file, a file that does not exist yet is simply an empty store.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<AnswerStore> {
+        let path = path.as_ref().to_path_buf();
+        let days = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(AnswerStore { path, days })
+    }
+
+    // Write the answers back to the file they were loaded from, creating its directory if needed.
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.days)?;
+        fs::write(&self.path, json + "\n")
+    }
+
+    pub fn get(&self, day: u32, part: u8) -> Option<&Answer> {
+        self.days.get(&day).and_then(|a| a.get(part))
+    }
+
+    // Record an answer as accepted, unimplemented answers are never stored.
+    pub fn accept(&mut self, day: u32, part: u8, answer: Answer) {
+        if !answer.is_implemented() {
+            return;
+        }
+        let entry = self.days.entry(day).or_default();
+        match part {
+            1 => entry.part1 = Some(answer),
+            2 => entry.part2 = Some(answer),
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("mistletoe-store-{}.json", std::process::id()));
+        let mut store = AnswerStore::load(&path).unwrap();
+        assert_eq!(store.get(5, 1), None);
+
+        store.accept(5, 1, Answer::from(35u64));
+        store.accept(5, 2, Answer::from(46u64));
+        store.accept(20, 1, Answer::Unimplemented);
+        store.save().unwrap();
+
+        let loaded = AnswerStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, store);
+        assert_eq!(loaded.get(5, 2), Some(&Answer::from(46)));
+        assert_eq!(loaded.get(20, 1), None);
+    }
+}
@@ -0,0 +1,321 @@
+// This module understands the responses to submitted answers, and keeps a log of every submission.
+// The log lives at `answers/<year>-submissions.json`. Before anything is sent it is checked
+// against the log: a rejected answer is never sent twice, and "too high" or "too low" verdicts
+// give bounds that every later answer has to fall between.
+use super::answer::Answer;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// What the website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without a hint in which direction.
+    Wrong,
+    // Nothing was checked, another answer can be sent after waiting.
+    RateLimited(Duration),
+    // The part was solved before, so the answer was not checked.
+    AlreadySolved,
+    // The response did not look like any of the above, the text of the page is kept.
+    Unknown(String),
+}
+
+impl Verdict {
+    // Whether the answer is known to be wrong, and should never be sent again.
+    pub fn is_rejection(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown(text) => write!(f, "unrecognised response: {text}"),
+        }
+    }
+}
+
+static WAIT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:(\d+)m )?(\d+)s left to wait").unwrap());
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+// Work out the verdict from the page returned after submitting an answer.
+pub fn parse_response(html: &str) -> Verdict {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let text = TAG.replace_all(article, "");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        let wait = WAIT.captures(&text).map_or(Duration::ZERO, |c| {
+            let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+            let seconds: u64 = c[2].parse().unwrap_or(0);
+            Duration::from_secs(minutes * 60 + seconds)
+        });
+        Verdict::RateLimited(wait)
+    } else if text.contains("Did you already complete it?") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown(text.chars().take(200).collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u32,
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    // Seconds since the Unix epoch.
+    pub at: u64,
+}
+
+// Why an answer will not be sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    // This exact answer was sent before and was wrong.
+    Rejected(Verdict),
+    // The part is already solved, with this answer.
+    Solved(Answer),
+    // Earlier verdicts show the answer has to be above the first and below the second bound.
+    OutOfBounds(Option<i128>, Option<i128>),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Rejected(verdict) => {
+                write!(f, "this answer was already rejected as {verdict}")
+            }
+            Refusal::Solved(answer) => write!(f, "this part was already solved with {answer}"),
+            Refusal::OutOfBounds(low, high) => {
+                let bound = |b: &Option<i128>| b.map_or("?".to_string(), |b| b.to_string());
+                write!(
+                    f,
+                    "the answer has to be between {} and {}, exclusive",
+                    bound(low),
+                    bound(high)
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+// Get the default location of the submission log for a given year.
+pub fn path_for(year: u32) -> PathBuf {
+    PathBuf::from(format!("answers/{year}-submissions.json"))
+}
+
+impl SubmissionLog {
+    // Load the log from a file, a file that does not exist yet is simply an empty log.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<SubmissionLog> {
+        let path = path.as_ref().to_path_buf();
+        let submissions = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(SubmissionLog { path, submissions })
+    }
+
+    // Write the log back to the file it was loaded from, creating its directory if needed.
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.submissions)?;
+        fs::write(&self.path, json + "\n")
+    }
+
+    // Get every submission for a part, oldest first.
+    pub fn history(&self, day: u32, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.day == day && s.part == part)
+    }
+
+    // Get the exclusive bounds a numeric answer has to fall between, from earlier verdicts.
+    pub fn bounds(&self, day: u32, part: u8) -> (Option<i128>, Option<i128>) {
+        let numbers = |verdict: Verdict| {
+            self.history(day, part)
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| match s.answer {
+                    Answer::Number(n) => Some(n),
+                    _ => None,
+                })
+        };
+        (
+            numbers(Verdict::TooLow).max(),
+            numbers(Verdict::TooHigh).min(),
+        )
+    }
+
+    // Check whether an answer is worth sending, based on everything that was sent before.
+    pub fn check(&self, day: u32, part: u8, answer: &Answer) -> Result<(), Refusal> {
+        if let Some(solved) = self
+            .history(day, part)
+            .find(|s| s.verdict == Verdict::Correct)
+        {
+            return Err(Refusal::Solved(solved.answer.clone()));
+        }
+        if let Some(rejected) = self
+            .history(day, part)
+            .find(|s| s.verdict.is_rejection() && s.answer == *answer)
+        {
+            return Err(Refusal::Rejected(rejected.verdict.clone()));
+        }
+        if let Answer::Number(n) = answer {
+            let (low, high) = self.bounds(day, part);
+            if low.is_some_and(|low| *n <= low) || high.is_some_and(|high| *n >= high) {
+                return Err(Refusal::OutOfBounds(low, high));
+            }
+        }
+        Ok(())
+    }
+
+    // Add a submission to the log, stamped with the current time.
+    pub fn record(&mut self, day: u32, part: u8, answer: Answer, verdict: Verdict) {
+        let at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.submissions.push(Submission {
+            day,
+            part,
+            answer,
+            verdict,
+            at,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn parse_responses() {
+        let correct = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(parse_response(&correct), Verdict::Correct);
+        let high =
+            page("That's not the right answer; your answer is too high.  If you're stuck, ...");
+        assert_eq!(parse_response(&high), Verdict::TooHigh);
+        let low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(parse_response(&low), Verdict::TooLow);
+        let wrong = page("That's not the right answer.  If you're stuck, make sure ...");
+        assert_eq!(parse_response(&wrong), Verdict::Wrong);
+        let limited = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.");
+        assert_eq!(
+            parse_response(&limited),
+            Verdict::RateLimited(Duration::from_secs(65))
+        );
+        let seconds = page("You gave an answer too recently.  You have 32s left to wait.");
+        assert_eq!(
+            parse_response(&seconds),
+            Verdict::RateLimited(Duration::from_secs(32))
+        );
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_response(&solved), Verdict::AlreadySolved);
+        assert!(
+            matches!(parse_response("<p>Maintenance</p>"), Verdict::Unknown(text) if text == "Maintenance")
+        );
+    }
+
+    #[test]
+    fn refuse_known_answers() {
+        let mut log = SubmissionLog::default();
+        assert_eq!(log.check(5, 1, &Answer::from(100)), Ok(()));
+
+        log.record(5, 1, Answer::from(100), Verdict::TooHigh);
+        log.record(5, 1, Answer::from(40), Verdict::TooLow);
+        log.record(5, 1, Answer::from(60), Verdict::Wrong);
+        log.record(
+            5,
+            1,
+            Answer::from(70),
+            Verdict::RateLimited(Duration::from_secs(30)),
+        );
+        assert_eq!(log.bounds(5, 1), (Some(40), Some(100)));
+        assert_eq!(log.bounds(5, 2), (None, None));
+
+        assert_eq!(
+            log.check(5, 1, &Answer::from(60)),
+            Err(Refusal::Rejected(Verdict::Wrong))
+        );
+        assert_eq!(
+            log.check(5, 1, &Answer::from(100)),
+            Err(Refusal::Rejected(Verdict::TooHigh))
+        );
+        assert_eq!(
+            log.check(5, 1, &Answer::from(150)),
+            Err(Refusal::OutOfBounds(Some(40), Some(100)))
+        );
+        assert_eq!(
+            log.check(5, 1, &Answer::from(30)),
+            Err(Refusal::OutOfBounds(Some(40), Some(100)))
+        );
+        assert_eq!(log.check(5, 1, &Answer::from(70)), Ok(()));
+        assert_eq!(log.check(5, 1, &Answer::from("text")), Ok(()));
+        assert_eq!(log.check(5, 2, &Answer::from(150)), Ok(()));
+
+        log.record(5, 1, Answer::from(70), Verdict::Correct);
+        assert_eq!(
+            log.check(5, 1, &Answer::from(71)),
+            Err(Refusal::Solved(Answer::from(70)))
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("mistletoe-submissions-{}.json", std::process::id()));
+        let mut log = SubmissionLog::load(&path).unwrap();
+        log.record(5, 1, Answer::from(35), Verdict::TooLow);
+        log.record(
+            5,
+            1,
+            Answer::from(36),
+            Verdict::RateLimited(Duration::from_secs(5)),
+        );
+        log.save().unwrap();
+
+        let loaded = SubmissionLog::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, log);
+    }
+}
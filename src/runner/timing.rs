@@ -0,0 +1,112 @@
+// This module measures how long each step of a solution takes.
+// Every step can be repeated a number of times, in which case the median duration is reported.
+use serde::{Serialize, Serializer};
+use std::ops::Add;
+use std::time::{Duration, Instant};
+
+// Durations are written to JSON as whole nanoseconds.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Timings {
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Duration,
+    #[serde(rename = "part1_ns", serialize_with = "nanos")]
+    pub part1: Duration,
+    #[serde(rename = "part2_ns", serialize_with = "nanos")]
+    pub part2: Duration,
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+impl Add for Timings {
+    type Output = Timings;
+
+    fn add(self, other: Timings) -> Timings {
+        Timings {
+            parse: self.parse + other.parse,
+            part1: self.part1 + other.part1,
+            part2: self.part2 + other.part2,
+        }
+    }
+}
+
+// Run the given function `repeats` times, returning the last result and the median duration.
+// A repeat count of zero is treated as one, so there is always a result.
+pub fn measure<T>(repeats: usize, mut f: impl FnMut() -> T) -> (T, Duration) {
+    let mut samples = Vec::with_capacity(repeats.max(1));
+    let mut result = None;
+
+    for _ in 0..repeats.max(1) {
+        let start = Instant::now();
+        let value = f();
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+
+    (result.unwrap(), median(&mut samples))
+}
+
+// Get the median of a set of durations, averaging the two middle values for an even amount.
+pub fn median(samples: &mut [Duration]) -> Duration {
+    if samples.is_empty() {
+        return Duration::ZERO;
+    }
+
+    samples.sort();
+    let mid = samples.len() / 2;
+    if samples.len().is_multiple_of(2) {
+        (samples[mid - 1] + samples[mid]) / 2
+    } else {
+        samples[mid]
+    }
+}
+
+// Format a duration with a sensible unit and two decimals, e.g. "1.23ms".
+pub fn format(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_odd_and_even() {
+        let ms = Duration::from_millis;
+        assert_eq!(median(&mut [ms(5), ms(1), ms(3)]), ms(3));
+        assert_eq!(median(&mut [ms(4), ms(1), ms(2), ms(8)]), ms(3));
+        assert_eq!(median(&mut []), Duration::ZERO);
+    }
+
+    #[test]
+    fn measure_repeats() {
+        let mut calls = 0;
+        let (result, _) = measure(5, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(result, 5);
+
+        let (result, _) = measure(0, || 42);
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn totals() {
+        let ms = Duration::from_millis;
+        let a = Timings {
+            parse: ms(1),
+            part1: ms(2),
+            part2: ms(3),
+        };
+        assert_eq!(a.total(), ms(6));
+        assert_eq!((a + a).part2, ms(6));
+    }
+}
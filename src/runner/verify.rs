@@ -0,0 +1,161 @@
+// This module checks the answers of the solutions against the accepted answers in the store.
+// It is meant to be run after refactors, so any change to a previously accepted answer is caught.
+use super::answer::Answer;
+use super::solution::Runnable;
+use super::store::AnswerStore;
+use super::Outcome;
+use crate::error::Result;
+use serde::Serialize;
+use std::fmt;
+
+// Written to JSON as e.g. `{"status": "fail", "expected": 47, "actual": 46}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Status {
+    Pass,
+    Fail { expected: Answer, actual: Answer },
+    // There is no accepted answer to compare against yet.
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {expected}, got {actual})")
+            }
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+// The verification result of both parts of a single day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub part1: Status,
+    pub part2: Status,
+}
+
+impl Report {
+    pub fn failed(&self) -> bool {
+        matches!(self.part1, Status::Fail { .. }) || matches!(self.part2, Status::Fail { .. })
+    }
+}
+
+// Compare a computed answer against the accepted one.
+pub fn check(accepted: Option<&Answer>, actual: Answer) -> Status {
+    match accepted {
+        Some(expected) if *expected == actual => Status::Pass,
+        Some(expected) => Status::Fail {
+            expected: expected.clone(),
+            actual,
+        },
+        None => Status::Missing,
+    }
+}
+
+// Run a solution against the given input and check both parts against the store.
+pub fn verify(solution: &dyn Runnable, data: &[String], store: &AnswerStore) -> Result<Report> {
+    let input = solution.parse(data)?;
+    let day = solution.day();
+    Ok(Report {
+        year: solution.year(),
+        day,
+        part1: check(store.get(day, 1), solution.part1(&input)?),
+        part2: check(store.get(day, 2), solution.part2(&input)?),
+    })
+}
+
+// The status of both parts of an outcome, parts that were not run have none.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Verification {
+    pub part1: Option<Status>,
+    pub part2: Option<Status>,
+}
+
+impl Verification {
+    pub fn failed(&self) -> bool {
+        [&self.part1, &self.part2]
+            .into_iter()
+            .any(|status| matches!(status, Some(Status::Fail { .. })))
+    }
+}
+
+// Check the answers of an outcome against the store, without running anything again.
+pub fn verification(outcome: &Outcome, store: &AnswerStore) -> Verification {
+    let status = |part: u8, answer: &Option<Answer>| {
+        answer
+            .clone()
+            .map(|answer| check(store.get(outcome.day, part), answer))
+    };
+    Verification {
+        part1: status(1, &outcome.part1),
+        part2: status(2, &outcome.part2),
+    }
+}
+
+pub fn print_report(report: &Report) {
+    println!(
+        "{} Day {:02}: Part 1: {} | Part 2: {}",
+        report.year, report.day, report.part1, report.part2
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::utility;
+    use crate::solutions;
+
+    #[test]
+    fn verify_example() {
+        let solution = solutions::find(2023, 5).unwrap();
+        let data = utility::files::try_read_file("testdata/2023/day05.txt").unwrap();
+
+        let mut store = AnswerStore::default();
+        store.accept(5, 1, Answer::from(35));
+        let report = verify(solution, &data, &store).unwrap();
+        assert_eq!(report.part1, Status::Pass);
+        assert_eq!(report.part2, Status::Missing);
+        assert!(!report.failed());
+
+        store.accept(5, 2, Answer::from(47));
+        let report = verify(solution, &data, &store).unwrap();
+        assert_eq!(
+            report.part2,
+            Status::Fail {
+                expected: Answer::from(47),
+                actual: Answer::from(46),
+            }
+        );
+        assert!(report.failed());
+    }
+
+    #[test]
+    fn verify_outcome() {
+        let solution = solutions::find(2023, 5).unwrap();
+        let data = utility::files::try_read_file("testdata/2023/day05.txt").unwrap();
+        let options = crate::runner::Options {
+            part: Some(1),
+            ..Default::default()
+        };
+        let outcome = crate::runner::execute(solution, &data, &options).unwrap();
+
+        let mut store = AnswerStore::default();
+        store.accept(5, 1, Answer::from(36));
+        let verification = verification(&outcome, &store);
+        assert!(verification.failed());
+        assert_eq!(verification.part2, None);
+        assert_eq!(
+            serde_json::to_string(&verification).unwrap(),
+            r#"{"part1":{"status":"fail","expected":36,"actual":35},"part2":null}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Status::Missing).unwrap(),
+            r#"{"status":"missing"}"#
+        );
+    }
+}
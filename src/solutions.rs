@@ -1,26 +1,97 @@
-// Have to list them out due to finicky workings of dirmod.
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
\ No newline at end of file
+// Every year has its own module, which registers each of its days exactly once.
+// The runner, the benchmark harness and the tests all iterate over the combined registry.
+use crate::runner::solution::Runnable;
+
+// Declares the module of every day in a year, and adds them to the registry of that year.
+macro_rules! register {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        // All registered solutions of the year, in the order they should be run.
+        pub static SOLUTIONS: &[&dyn Runnable] = &[$(&$module::$solution),*];
+    };
+}
+
+pub mod y2023;
+
+// The registries of every year, oldest first.
+static YEARS: &[&[&dyn Runnable]] = &[y2023::SOLUTIONS];
+
+// Get every registered solution.
+pub fn all() -> impl Iterator<Item = &'static dyn Runnable> {
+    YEARS.iter().flat_map(|year| year.iter().copied())
+}
+
+// Get only the registered solutions that match the given predicate.
+pub fn filter<F>(predicate: F) -> impl Iterator<Item = &'static dyn Runnable>
+where
+    F: Fn(&dyn Runnable) -> bool,
+{
+    all().filter(move |s| predicate(*s))
+}
+
+// Find the solution for a specific day of a year, if one is registered.
+pub fn find(year: u32, day: u32) -> Option<&'static dyn Runnable> {
+    all().find(|s| s.year() == year && s.day() == day)
+}
+
+// Get every year with at least one registered solution, oldest first.
+pub fn years() -> impl Iterator<Item = u32> {
+    YEARS
+        .iter()
+        .filter_map(|year| year.first().map(|s| s.year()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::utility;
+
+    #[test]
+    fn registry_is_ordered_and_unique() {
+        let keys: Vec<(u32, u32)> = all().map(|s| (s.year(), s.day())).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(filter(|s| s.year() == 2023).count(), 25);
+        assert!(years().collect::<Vec<_>>().windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn registry_parses_examples() {
+        for solution in all() {
+            let path = crate::runner::example_path(solution);
+            let data = utility::files::try_read_file(&path)
+                .unwrap_or_else(|e| panic!("Could not read {path}: {e}"));
+            if let Err(e) = solution.parse(&data) {
+                panic!("Could not parse {path}: {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_input_is_located() {
+        let data = vec!["seeds: 79 14 55 x3".to_string()];
+        let e = find(2023, 5).unwrap().parse(&data).err().unwrap();
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 17: expected a number, found \"x3\""
+        );
+
+        let data = vec!["2413".to_string(), "32?5".to_string()];
+        let e = find(2023, 17).unwrap().parse(&data).err().unwrap();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 3: invalid grid character '?'"
+        );
+    }
+
+    #[test]
+    fn find_and_filter() {
+        assert_eq!(
+            find(2023, 5).map(|s| s.name()),
+            Some("If You Give A Seed A Fertilizer")
+        );
+        assert!(find(2023, 26).is_none());
+        assert!(find(2014, 5).is_none());
+        assert_eq!(filter(|s| s.day() > 20).count(), 5);
+    }
+}
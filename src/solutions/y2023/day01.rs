@@ -0,0 +1,85 @@
+// https://adventofcode.com/2023/day/01
+use crate::error::Result;
+use crate::runner::{answer::Answer, solution::Solution};
+use std::collections::VecDeque;
+
+const NUMBERS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<String>> {
+        Ok(data.to_vec())
+    }
+
+    fn part1(&self, data: &Vec<String>) -> Result<Answer> {
+        let p1: u32 = data
+            .iter()
+            .filter_map(|s| {
+                let f = s.chars().find(|c| c.is_ascii_digit());
+                let l = s.chars().rev().find(|c| c.is_ascii_digit());
+                match (f, l) {
+                    (Some(f), Some(l)) => {
+                        Some(f.to_digit(10).unwrap() * 10 + l.to_digit(10).unwrap())
+                    }
+                    _ => None,
+                }
+            })
+            .sum();
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &Vec<String>) -> Result<Answer> {
+        let mut p2 = 0;
+
+        for line in data {
+            let mut q = VecDeque::new();
+            let mut i = 0;
+
+            while i < line.len() {
+                if let Some(c) = line[i..].chars().next() {
+                    if c.is_ascii_digit() {
+                        q.push_back(c.to_digit(10).unwrap() as i64);
+                        i += 1;
+                    } else {
+                        // If we do not have a digit, check if we have a word that is a number in the numbers vector starting at the current index
+                        // If it finds a match, push the index of the number to the queue and increment the index by one
+                        for (j, n) in NUMBERS.iter().enumerate() {
+                            if line[i..].starts_with(n) {
+                                q.push_back(j as i64);
+                                i += 1;
+                                break;
+                            }
+                        }
+                        i += 1;
+                    }
+                }
+            }
+
+            if let (Some(first), Some(last)) = (q.front(), q.back()) {
+                p2 += first * 10 + last;
+            }
+        }
+
+        let p2: i32 = p2 as i32;
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Trebuchet?!"
+    }
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
@@ -0,0 +1,89 @@
+// https://adventofcode.com/2023/day/02
+use crate::error::Result;
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+use std::collections::HashMap;
+
+const MAX_RED: i32 = 12;
+const MAX_GREEN: i32 = 13;
+const MAX_BLUE: i32 = 14;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(i32, i32, i32, i32)>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<(i32, i32, i32, i32)>> {
+        let mut result = Vec::new();
+
+        for line in parsing::lines(data) {
+            let mut max_counts = HashMap::new();
+            max_counts.insert("red", 0);
+            max_counts.insert("green", 0);
+            max_counts.insert("blue", 0);
+
+            // Split off the game id, which follows the word "Game".
+            let (game, rest) = line.split_once(": ")?;
+            let game_id = line.parse::<i32>(game.trim_start_matches("Game "))?;
+
+            // Process the rest of the line, it will always be # word.
+            // Based on what the word is (red, green, blue), update the max value for that category.
+            let pairs = rest
+                .split([',', ';', ' '])
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<&str>>();
+
+            for pair in pairs.chunks(2) {
+                let &[count, color] = pair else {
+                    return Err(line.error(pair[0], "expected a count followed by a colour"));
+                };
+                let count = line.parse::<i32>(count)?;
+                let entry = max_counts
+                    .get_mut(color)
+                    .ok_or_else(|| line.error(color, format!("unknown colour {color:?}")))?;
+                *entry = (*entry).max(count);
+            }
+
+            let max_red = *max_counts.get("red").unwrap_or(&0);
+            let max_green = *max_counts.get("green").unwrap_or(&0);
+            let max_blue = *max_counts.get("blue").unwrap_or(&0);
+
+            result.push((game_id, max_red, max_green, max_blue));
+        }
+
+        Ok(result)
+    }
+
+    fn part1(&self, data: &Vec<(i32, i32, i32, i32)>) -> Result<Answer> {
+        let p1: i32 = data
+            .iter()
+            .filter(|&&(_, red, green, blue)| {
+                red <= MAX_RED && green <= MAX_GREEN && blue <= MAX_BLUE
+            })
+            .map(|&(game_id, _, _, _)| game_id)
+            .sum();
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &Vec<(i32, i32, i32, i32)>) -> Result<Answer> {
+        let p2: i32 = data
+            .iter()
+            .map(|&(_, red, green, blue)| red * green * blue)
+            .sum();
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Cube Conundrum"
+    }
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
@@ -0,0 +1,113 @@
+// https://adventofcode.com/2023/day/03
+use crate::error::Result;
+use crate::runner::{answer::Answer, solution::Solution};
+use std::collections::HashMap;
+
+// Scan the schematic for every number adjacent to a symbol, alongside the position of an adjacent gear if there is one.
+fn part_numbers(data: &HashMap<(i32, i32), char>) -> Vec<(i32, Option<(i32, i32)>)> {
+    let mut numbers = Vec::new();
+
+    // Find the bounds of the grid, so we can iterate over it and not reach any ends.
+    let max_x = data.keys().map(|(x, _)| x).max().unwrap_or(&0);
+    let max_y = data.keys().map(|(_, y)| y).max().unwrap_or(&0);
+
+    // Go through each coordinate and get the number, and then get proximity to symbols.
+    for x in 0..=*max_x {
+        let mut num = String::new();
+        let mut valid = false;
+        let mut gear_pos: Option<(i32, i32)> = None;
+
+        for y in 0..=*max_y {
+            // Checks the character at the coordinate, and if it's a digit, we add it to the number.
+            if let Some(&c) = data.get(&(x, y)) {
+                if c.is_ascii_digit() {
+                    num.push(c);
+                    // Need to check neighbouring coordinates.
+                    if !valid {
+                        for x1 in -1..=1 {
+                            for y1 in -1..=1 {
+                                // Find any non-digit characters, and if they're not a dot, we can add the number.
+                                if let Some(&c2) = data.get(&(x + x1, y + y1)) {
+                                    if !c2.is_ascii_digit() && c2 != '.' {
+                                        valid = true;
+                                        if c2 == '*' {
+                                            gear_pos = Some((x + x1, y + y1));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                } else {
+                    // Once we hit a non-digit, we can add the number to the list.
+                    if valid {
+                        numbers.push((num.parse().unwrap_or(0), gear_pos));
+                        // Need to reset flags
+                        valid = false;
+                        gear_pos = None;
+                    }
+                    num.clear();
+                }
+            }
+        }
+
+        // Check last character.
+        if valid {
+            numbers.push((num.parse().unwrap_or(0), gear_pos));
+        }
+    }
+
+    numbers
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = HashMap<(i32, i32), char>;
+
+    fn parse(&self, data: &[String]) -> Result<HashMap<(i32, i32), char>> {
+        let mut map = HashMap::new();
+        for (x, line) in data.iter().enumerate() {
+            for (y, c) in line.chars().enumerate() {
+                map.insert((x as i32, y as i32), c);
+            }
+        }
+        Ok(map)
+    }
+
+    fn part1(&self, data: &HashMap<(i32, i32), char>) -> Result<Answer> {
+        let p1: i32 = part_numbers(data).iter().map(|(n, _)| n).sum();
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &HashMap<(i32, i32), char>) -> Result<Answer> {
+        // Part 2 is about finding gear-ratios, essentially look for any gear with 2 numbers near it, and multiply them then add to total.
+        let mut gears: HashMap<(i32, i32), Vec<i32>> = HashMap::new();
+        for (n, gear_pos) in part_numbers(data) {
+            if let Some(pos) = gear_pos {
+                gears.entry(pos).or_default().push(n);
+            }
+        }
+
+        let p2: i32 = gears
+            .values()
+            .filter(|v| v.len() == 2)
+            .map(|v| v[0] * v[1])
+            .sum();
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Gear Ratios"
+    }
+
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
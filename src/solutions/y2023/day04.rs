@@ -0,0 +1,74 @@
+// https://adventofcode.com/2023/day/04
+use crate::error::Result;
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+use std::collections::HashSet;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<(HashSet<i32>, HashSet<i32>)>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<(HashSet<i32>, HashSet<i32>)>> {
+        let mut result = Vec::new();
+
+        for line in parsing::lines(data) {
+            let (_, numbers) = line.split_once(":")?;
+            let (winning, given) = numbers
+                .split_once('|')
+                .ok_or_else(|| line.error(numbers, "expected '|'"))?;
+
+            let wnums = line.numbers::<i32>(winning)?.into_iter().collect();
+            let gnums = line.numbers::<i32>(given)?.into_iter().collect();
+
+            result.push((wnums, gnums));
+        }
+
+        Ok(result)
+    }
+
+    fn part1(&self, data: &Vec<(HashSet<i32>, HashSet<i32>)>) -> Result<Answer> {
+        let p1: i32 = data
+            .iter()
+            .map(|(wnums, gnums)| {
+                let overlap = wnums.intersection(gnums).count();
+                if overlap > 0 {
+                    1 << (overlap - 1)
+                } else {
+                    0
+                }
+            })
+            .sum();
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &Vec<(HashSet<i32>, HashSet<i32>)>) -> Result<Answer> {
+        let mut card_counter = vec![1; data.len()];
+
+        // Iterate over current
+        for (i, (wnums, gnums)) in data.iter().enumerate() {
+            let winners = wnums.intersection(gnums).count(); // Get how many winning numbers we have.
+            for j in (i + 1)..(i + 1 + winners) {
+                // Increment the next n cards, multiple times if needed.
+                card_counter[j] += card_counter[i];
+            }
+        }
+
+        let p2: i32 = card_counter.iter().sum();
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Scratchcards"
+    }
+
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
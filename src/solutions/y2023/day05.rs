@@ -0,0 +1,153 @@
+// https://adventofcode.com/2023/day/05
+use crate::error::{Error, Result};
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+
+fn process_maps(n: u64, maps: &[BTreeMap<u64, (u64, u64)>]) -> u64 {
+    // Go through the maps array, one by one, so it processes seed-to-soil, then soil-to-fertilizer etc..
+    maps.iter().fold(n, |acc, map| {
+        // For each map, we need to keep the last processed one, so we can apply some new transformations to it.
+        match map.range(..acc + 1).last() {
+            // Get the highest key in the map that is less than our accumulator.
+            Some((&src, &(dst, len))) => {
+                // Calculate the offset, and figure out the new applied range and all that for the next map.
+                let offset = acc - src;
+                if offset < len {
+                    dst + offset
+                } else {
+                    acc
+                }
+            }
+            _ => acc, // Basically forces the 1 to 1 mapping to be applied instead.
+        }
+    })
+}
+
+fn process_maps_ranges(seeds: &[u64], maps: &[BTreeMap<u64, (u64, u64)>]) -> Vec<(u64, u64)> {
+    let mut ranges = seeds.chunks(2).map(|v| (v[0], v[1])).collect::<Vec<_>>();
+
+    for map in maps {
+        let mut new_ranges = Vec::with_capacity(ranges.len());
+
+        for (start, len) in ranges {
+            let mut current_start = start;
+
+            let mut iter = map.range(..start + len).peekable();
+
+            while current_start < start + len {
+                match iter.peek() {
+                    Some((&src, &(dst, n))) if src <= current_start => {
+                        let map_end = src + n;
+
+                        // Unmapped section.
+                        if current_start < src {
+                            new_ranges.push((
+                                current_start,
+                                std::cmp::min(start + len, src) - current_start,
+                            ));
+                            current_start = src;
+                        }
+
+                        // Mapped section.
+                        if current_start < map_end {
+                            new_ranges.push((
+                                dst + current_start - src,
+                                std::cmp::min(start + len, map_end) - current_start,
+                            ));
+                            current_start = map_end;
+                        }
+
+                        iter.next();
+                    }
+                    _ => {
+                        // No more mappings applicable, add the rest of the range
+                        new_ranges.push((current_start, start + len - current_start));
+                        break;
+                    }
+                }
+            }
+        }
+
+        ranges = new_ranges;
+    }
+
+    ranges
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<u64>, Vec<BTreeMap<u64, (u64, u64)>>);
+
+    fn parse(&self, data: &[String]) -> Result<(Vec<u64>, Vec<BTreeMap<u64, (u64, u64)>>)> {
+        // Need to get lines, and remove the empty lines, so we can split the data into sections for each mapping.
+        let lines: Vec<_> = parsing::lines(data).collect();
+        let mut sections = lines.split(|l| l.text.is_empty());
+
+        // Seed is the first line only..
+        let first = sections
+            .next()
+            .and_then(|section| section.first())
+            .ok_or_else(|| Error::parse(1, 1, "expected a line of seeds"))?;
+        let (_, seeds) = first.split_once(": ")?;
+        let seeds = first.numbers::<u64>(seeds)?;
+        if !seeds.len().is_multiple_of(2) {
+            return Err(first.error(first.text, "expected pairs of seeds"));
+        }
+
+        // Now parse the maps into a vector of hashmaps.
+        // Each hash-map has format of destination -> (source, amount)
+        let maps = sections
+            .map(|section| {
+                section
+                    .iter()
+                    .skip(1)
+                    .map(|line| match line.numbers::<u64>(line.text)?[..] {
+                        [dst, src, len] => Ok((src, (dst, len))),
+                        _ => Err(line.error(line.text, "expected three numbers")),
+                    })
+                    .collect::<Result<BTreeMap<_, _>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((seeds, maps))
+    }
+
+    fn part1(&self, data: &(Vec<u64>, Vec<BTreeMap<u64, (u64, u64)>>)) -> Result<Answer> {
+        // Process all the maps so we get the locations for each seed.
+        let p1: u64 = data
+            .0
+            .par_iter()
+            .map(|&s| process_maps(s, &data.1))
+            .min()
+            .ok_or_else(|| Error::unsolvable("there are no seeds"))?;
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &(Vec<u64>, Vec<BTreeMap<u64, (u64, u64)>>)) -> Result<Answer> {
+        // For part 2, we need to operate on a seed of values. That is the data.0 vector is actually a set of ranges...
+        // So we just do the thing for all the seeds, and then find the minimum value.
+        let p2: u64 = process_maps_ranges(&data.0, &data.1)
+            .par_iter()
+            .map(|&(s, _)| s)
+            .min()
+            .ok_or_else(|| Error::unsolvable("there are no seed ranges"))?;
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "If You Give A Seed A Fertilizer"
+    }
+
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
@@ -0,0 +1,70 @@
+// https://adventofcode.com/2023/day/06
+use crate::error::{Error, Result};
+use crate::library::containers::vector::concat_slice_to_num;
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+
+pub fn ways_to_win(t: u64, d: u64) -> u64 {
+    // This is actually an equation of a quadratic form: -x^2 + tx - d = 0
+    // Just need to solve for x and we have the number of ways to win. Since it's essentially bounding our two ends.
+    let m = ((t.pow(2) - 4 * d) as f64).sqrt();
+    ((t as f64 + m) / 2.0 - 1.0).ceil() as u64 - ((t as f64 - m) / 2.0 + 1.0).floor() as u64 + 1
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(&self, data: &[String]) -> Result<(Vec<u64>, Vec<u64>)> {
+        // Both lines start with a label, followed by the numbers of every race.
+        let lines: Vec<_> = parsing::lines(data).collect();
+        let &[times, distances] = &lines[..] else {
+            let message = "expected a line of times and a line of distances";
+            return Err(Error::parse(lines.len().min(2) + 1, 1, message));
+        };
+        let numbers = |line: parsing::Line| {
+            let (_, numbers) = line.split_once(":")?;
+            line.numbers::<u64>(numbers)
+        };
+
+        let times = numbers(times)?;
+        let distances = numbers(distances)?;
+        if times.len() != distances.len() {
+            return Err(Error::parse(2, 1, "expected as many distances as times"));
+        }
+
+        Ok((times, distances))
+    }
+
+    fn part1(&self, data: &(Vec<u64>, Vec<u64>)) -> Result<Answer> {
+        // The p1 is operating on the vector as a set of pairs.
+        let p1: u64 = data
+            .0
+            .iter()
+            .zip(data.1.iter())
+            .map(|(t, d)| ways_to_win(*t, *d))
+            .product();
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &(Vec<u64>, Vec<u64>)) -> Result<Answer> {
+        // For p2, instead of just going off a zip, we need to assume we have two indexes..
+        let p2 = ways_to_win(concat_slice_to_num(&data.0), concat_slice_to_num(&data.1));
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Wait For It"
+    }
+
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
@@ -1,7 +1,9 @@
 // https://adventofcode.com/2023/day/07
-use std::cmp::Ordering;
+use crate::error::Result;
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
 use rayon::prelude::*;
-use crate::library::utility;
+use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum HandType {
@@ -66,9 +68,7 @@ pub fn rank(hand: &Hand, p2: bool) -> (HandType, Vec<i32>) {
                 (HandType::FourOfAKind, rank)
             }
             _ if (count.contains(&3) && count.contains(&2))
-                || (jokers >= 1
-                    && jokers < 3
-                    && count.iter().filter(|&n| *n == 2).count() == 4) =>
+                || ((1..3).contains(&jokers) && count.iter().filter(|&n| *n == 2).count() == 4) =>
             {
                 (HandType::FullHouse, rank)
             }
@@ -115,70 +115,49 @@ fn compute_sum(data: &[Hand], p2: bool) -> i32 {
         .sum()
 }
 
-pub fn solve(data: &[Hand]) -> (i32, i32) {
-    let p1 = compute_sum(data, false);
-    let p2 = compute_sum(data, true);
+pub struct Day07;
 
-    (p1, p2)
-}
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<Hand>> {
+        let mut hands = Vec::new();
 
-pub fn parse(data: &[String]) -> Vec<Hand> {
-    let mut hands = Vec::new();
-
-    for line in data {
-        // Split the string into two parts: the string key and the integer value
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() == 2 {
-            if let Ok(value) = parts[1].parse::<i32>() {
-                // Insert the key and value into the map
-                // hands.push((parts[0].to_string(), value));
-                hands.push(Hand {
-                    cards: parts[0].to_string(),
-                    bid: value,
-                });
+        for line in parsing::lines(data) {
+            // Split the string into two parts: the cards and the bid.
+            let cards = line.word(0)?;
+            let bid = line.parse::<i32>(line.word(1)?)?;
+            if let Some(i) = cards.find(|c| card_value(c, false) == 0) {
+                let card = cards[i..].chars().next().unwrap_or_default();
+                return Err(line.error(&cards[i..], format!("invalid card {card:?}")));
             }
+
+            hands.push(Hand {
+                cards: cards.to_string(),
+                bid,
+            });
         }
+
+        Ok(hands)
     }
 
-    hands
-}
+    fn part1(&self, data: &Vec<Hand>) -> Result<Answer> {
+        Ok(compute_sum(data, false).into())
+    }
 
-#[allow(dead_code)]
-pub fn run() {
-    let res = solve(&parse(&utility::files::read_file("data/day07.txt")));
-    println!("Day 07:\nStar 1: {}\nStar 2: {}\n", res.0, res.1);
-}
+    fn part2(&self, data: &Vec<Hand>) -> Result<Answer> {
+        Ok(compute_sum(data, true).into())
+    }
 
-#[allow(dead_code)]
-pub fn benchmark(c: &mut criterion::Criterion) {
-    let data = parse(&utility::files::read_file("data/day07.txt"));
-    c.bench_function("Day 07 - solve:", |b| b.iter(|| solve(&data)));
-    c.bench_function("Day 07 - parse & solve:", |b| {
-        b.iter(|| {
-            let data = parse(&utility::files::read_file("data/day07.txt"));
-            solve(&data)
-        })
-    });
-}
+    fn name(&self) -> &'static str {
+        "Camel Cards"
+    }
 
-#[cfg(test)]
-mod tests {
-    #[allow(unused_imports)]
-    use super::*;
-
-    #[test]
-    fn part1() {
-        let expected = 6440;
-        let res = solve(&parse(&utility::files::read_file("testdata/day07.txt")));
-        assert_eq!(res.0, expected);
-        println!("Part 1: Expected: {}, Actual: {}", expected, res.0);
+    fn day(&self) -> u32 {
+        7
     }
 
-    #[test]
-    fn part2() {
-        let expected = 5905;
-        let res = solve(&parse(&utility::files::read_file("testdata/day07.txt")));
-        assert_eq!(res.1, expected);
-        println!("Part 2: Expected: {}, Actual: {}", expected, res.1);
+    fn year(&self) -> u32 {
+        2023
     }
 }
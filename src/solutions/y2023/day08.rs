@@ -0,0 +1,102 @@
+// https://adventofcode.com/2023/day/08
+use crate::error::{Error, Result};
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+pub fn get_path_size(
+    instructions: &[char],
+    map: &HashMap<String, (String, String)>,
+    start: &str,
+    end: &str,
+) -> Result<u64> {
+    let mut curr_node = start;
+    let mut i = 0;
+    let mut path_size = 0;
+
+    while !curr_node.ends_with(end) {
+        let curr_char = instructions[i];
+        let (left, right) = map
+            .get(curr_node)
+            .ok_or_else(|| Error::unsolvable(format!("node {curr_node} does not exist")))?;
+
+        curr_node = if curr_char == 'L' { left } else { right };
+        path_size += 1;
+
+        i = (i + 1) % instructions.len();
+    }
+
+    Ok(path_size)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<char>, HashMap<String, (String, String)>);
+
+    fn parse(&self, data: &[String]) -> Result<(Vec<char>, HashMap<String, (String, String)>)> {
+        let mut lines = parsing::lines(data);
+
+        // The first line is the instructions, made up of only left and right turns.
+        let first = lines
+            .next()
+            .ok_or_else(|| Error::parse(1, 1, "expected instructions"))?;
+        if let Some(i) = first.text.find(|c| c != 'L' && c != 'R') {
+            let c = first.text[i..].chars().next().unwrap_or_default();
+            return Err(first.error(&first.text[i..], format!("invalid instruction {c:?}")));
+        }
+        if first.text.is_empty() {
+            return Err(first.error(first.text, "expected instructions"));
+        }
+        let chars = first.text.chars().collect::<Vec<char>>();
+
+        let mut map = HashMap::new();
+
+        for line in lines.filter(|l| !l.text.is_empty()) {
+            let (key, value_part) = line.split_once(" = ")?;
+            let (left, right) = value_part
+                .trim_matches(|p| p == '(' || p == ')')
+                .split_once(", ")
+                .ok_or_else(|| line.error(value_part, "expected (left, right)"))?;
+            map.insert(key.to_string(), (left.to_string(), right.to_string()));
+        }
+
+        Ok((chars, map))
+    }
+
+    fn part1(&self, data: &(Vec<char>, HashMap<String, (String, String)>)) -> Result<Answer> {
+        let p1 = get_path_size(&data.0, &data.1, "AAA", "ZZZ")?;
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &(Vec<char>, HashMap<String, (String, String)>)) -> Result<Answer> {
+        let path_sizes: Vec<_> = data
+            .1
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .collect::<Vec<_>>()
+            .par_iter()
+            .map(|k| get_path_size(&data.0, &data.1, k, "Z"))
+            .collect::<Result<_>>()?;
+
+        let p2 = path_sizes
+            .iter()
+            .fold(1, |acc, &num| num::integer::lcm(acc, num));
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Haunted Wasteland"
+    }
+
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
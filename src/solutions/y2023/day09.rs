@@ -0,0 +1,76 @@
+// https://adventofcode.com/2023/day/09
+use crate::error::Result;
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+use rayon::prelude::*;
+
+// Generate differences between elements of a vector
+pub fn differences(vec: &[i32]) -> Vec<i32> {
+    vec.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+pub fn extrapolate(data: Vec<i32>) -> i32 {
+    // Generate the chain of difference vectors
+    let mut chain = vec![data];
+    while !chain
+        .last()
+        .unwrap()
+        .par_iter()
+        .all(|&x| x == chain.last().unwrap()[0])
+    {
+        chain.push(differences(chain.last().unwrap()));
+    }
+
+    // Backtrack and extrapolate the chain
+    for i in (0..chain.len() - 1).rev() {
+        let last_element = chain[i].last().unwrap() + chain[i + 1].last().unwrap();
+        chain[i].push(last_element);
+    }
+
+    // Last element of first vector is the extrapolated value
+    *chain.first().unwrap().last().unwrap()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<Vec<i32>>> {
+        // Read data into a vector of vectors of i32
+        parsing::lines(data)
+            .map(|line| match line.numbers::<i32>(line.text)? {
+                numbers if numbers.is_empty() => Err(line.error(line.text, "expected numbers")),
+                numbers => Ok(numbers),
+            })
+            .collect()
+    }
+
+    fn part1(&self, data: &Vec<Vec<i32>>) -> Result<Answer> {
+        // Sum of all vectors called with extrapolate
+        let p1: i32 = data.par_iter().map(|v| extrapolate(v.to_vec())).sum();
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &Vec<Vec<i32>>) -> Result<Answer> {
+        let p2: i32 = data
+            .par_iter()
+            .map(|v| extrapolate(v.iter().rev().copied().collect()))
+            .sum();
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Mirage Maintenance"
+    }
+
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
@@ -0,0 +1,168 @@
+// https://adventofcode.com/2023/day/10
+use crate::error::{Error, Result};
+use crate::library::containers::direction::Direction;
+use crate::library::containers::grid::{Grid, Position};
+use crate::runner::{answer::Answer, solution::Solution};
+
+// The directions a tile connects to, as one bit per direction.
+fn connections(c: char) -> u8 {
+    let bits = |dirs: [Direction; 2]| dirs.iter().fold(0, |mask, &dir| mask | bit(dir));
+    match c {
+        '|' => bits([Direction::Up, Direction::Down]),
+        '-' => bits([Direction::Left, Direction::Right]),
+        '7' => bits([Direction::Left, Direction::Down]),
+        'F' => bits([Direction::Right, Direction::Down]),
+        'L' => bits([Direction::Right, Direction::Up]),
+        'J' => bits([Direction::Left, Direction::Up]),
+        _ => 0,
+    }
+}
+
+fn bit(dir: Direction) -> u8 {
+    1 << dir as u8
+}
+
+fn connects(pipe: u8, dir: Direction) -> bool {
+    pipe & bit(dir) != 0
+}
+
+// The maze with the loop through the start already found, the loop is listed in the order it is walked.
+pub struct Maze {
+    tiles: Grid,
+    pipes: Grid<u8>,
+    on_loop: Grid<bool>,
+    path: Vec<Position>,
+}
+
+impl Maze {
+    fn new(tiles: Grid, start: Position) -> Result<Maze> {
+        let mut pipes = tiles.map(|&c| connections(c));
+
+        // The start connects to whichever of its neighbours connect back to it.
+        let joined = Direction::ALL
+            .into_iter()
+            .filter(|&dir| {
+                pipes
+                    .step(start, dir)
+                    .and_then(|next| pipes.get_pos(next))
+                    .is_some_and(|pipe| connects(pipe, dir.reverse()))
+            })
+            .fold(0, |mask, dir| mask | bit(dir));
+        *pipes.cell_mut(start.y, start.x).unwrap() = joined;
+
+        let path = walk(&pipes, start)?;
+        let mut on_loop = pipes.map(|_| false);
+        for pos in &path {
+            *on_loop.cell_mut(pos.y, pos.x).unwrap() = true;
+        }
+
+        Ok(Maze {
+            tiles,
+            pipes,
+            on_loop,
+            path,
+        })
+    }
+}
+
+// Follow the pipes from the start until we are back at it, every pipe has one way in and one way out.
+fn walk(pipes: &Grid<u8>, start: Position) -> Result<Vec<Position>> {
+    let broken = || Error::unsolvable("the start is not part of a loop");
+    let exit = |pipe: u8, entered: Option<Direction>| {
+        Direction::ALL
+            .into_iter()
+            .find(|&dir| connects(pipe, dir) && Some(dir.reverse()) != entered)
+    };
+
+    let mut path = vec![start];
+    let (mut pos, mut dir) = (
+        start,
+        exit(pipes.get_pos(start).unwrap(), None).ok_or_else(broken)?,
+    );
+    loop {
+        pos = pipes.step(pos, dir).ok_or_else(broken)?;
+        if pos == start {
+            return Ok(path);
+        }
+        let pipe = pipes.get_pos(pos).unwrap();
+        if !connects(pipe, dir.reverse()) {
+            return Err(broken());
+        }
+        dir = exit(pipe, Some(dir)).ok_or_else(broken)?;
+        path.push(pos);
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Maze;
+
+    fn parse(&self, data: &[String]) -> Result<Maze> {
+        // Only pipes, ground and the start are allowed.
+        let tiles = Grid::parse(data, "|-.SFJL7")?;
+
+        let starts: Vec<Position> = (0..tiles.height())
+            .flat_map(|y| (0..tiles.width()).map(move |x| Position::new(x, y)))
+            .filter(|pos| tiles.get_pos(*pos) == Some('S'))
+            .collect();
+        match starts[..] {
+            [start] => Maze::new(tiles, start),
+            _ => Err(Error::parse(
+                1,
+                1,
+                format!("expected one starting point, found {}", starts.len()),
+            )),
+        }
+    }
+
+    fn part1(&self, maze: &Maze) -> Result<Answer> {
+        Ok((maze.path.len() / 2).into())
+    }
+
+    fn part2(&self, maze: &Maze) -> Result<Answer> {
+        // Scan every row, we are inside the loop after crossing it an odd number of times. Only pipes
+        // connecting up count as a crossing, so a stretch like "L--7" counts once and "L--J" not at all.
+        let mut enclosed = 0;
+        for y in 0..maze.pipes.height() {
+            let mut inside = false;
+            for x in 0..maze.pipes.width() {
+                if maze.on_loop.get(y, x) == Some(true) {
+                    inside ^= maze
+                        .pipes
+                        .get(y, x)
+                        .is_some_and(|p| connects(p, Direction::Up));
+                } else if inside {
+                    enclosed += 1;
+                }
+            }
+        }
+
+        Ok(enclosed.into())
+    }
+
+    // Draw the way from the start to the farthest point of the loop, the rest of the loop is marked with '*'.
+    fn debug(&self, maze: &Maze) -> Result<Option<String>> {
+        let farthest = maze.path.len() / 2;
+        let (way, rest) = maze.path.split_at(farthest + 1);
+        Ok(Some(format!(
+            "{farthest} steps to the farthest point:\n{}",
+            maze.tiles
+                .highlight(rest.iter().copied(), '*')
+                .draw_path(way)
+                .to_string(true)
+        )))
+    }
+
+    fn name(&self) -> &'static str {
+        "Pipe Maze"
+    }
+
+    fn day(&self) -> u32 {
+        10
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
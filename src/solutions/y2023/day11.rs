@@ -0,0 +1,90 @@
+// https://adventofcode.com/2023/day/11
+use crate::error::Result;
+use crate::library::containers::point::Point;
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+const P1_SCALE: i64 = 2;
+const P2_SCALE: i64 = 1_000_000;
+
+type Coordinates = Point<i64>;
+
+// We need to be able to calculate the real coordinates based off the scaling of rows and columns without any galaxies in them.
+pub fn get_real_coords(
+    p: &Coordinates,
+    scale: i64,
+    x_set: &HashSet<i64>,
+    y_set: &HashSet<i64>,
+) -> Coordinates {
+    // Calculate the offset, that is the amount of numbers by which to shift our coordinate due to any empty row or columns.
+    let x_offset = (1..=p.x).filter(|&i| !x_set.contains(&i)).count() as i64;
+    let y_offset = (1..=p.y).filter(|&i| !y_set.contains(&i)).count() as i64;
+
+    // We return the new coordinate, but we have to multiply the offset by scale, and then subtract the offset for the original row or column from the scaled up version.
+    Point::new(
+        p.x + (x_offset * scale) - x_offset,
+        p.y + (y_offset * scale) - y_offset,
+    )
+}
+
+// Sum the distances between every pair of galaxies, with empty rows and columns scaled up by the given amount.
+pub fn total_distance(data: &[Coordinates], scale: i64) -> i64 {
+    // Generate hashsets once of the x and y coordinate directions which are needed to get actual coordinates.
+    let x_set: HashSet<_> = data.iter().map(|key| key.x).collect();
+    let y_set: HashSet<_> = data.iter().map(|key| key.y).collect();
+
+    // Calculate scaled coordinates before hand for everything.
+    let scaled: Vec<Coordinates> = data
+        .iter()
+        .map(|c| get_real_coords(c, scale, &x_set, &y_set))
+        .collect();
+
+    scaled
+        .iter()
+        .combinations(2)
+        .map(|c| c[0].manhattan(*c[1]))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Coordinates>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<Coordinates>> {
+        let mut vec = Vec::new();
+        for (x, line) in parsing::lines(data).enumerate() {
+            for (y, c) in line.text.chars().enumerate() {
+                // We only need to insert the '#' since the rest can be extrapolated from the HashMap itself.
+                match c {
+                    '#' => vec.push(Point::new(x as i64, y as i64)),
+                    '.' => (),
+                    _ => return Err(line.invalid_char(y, c)),
+                }
+            }
+        }
+        Ok(vec)
+    }
+
+    fn part1(&self, data: &Vec<Coordinates>) -> Result<Answer> {
+        Ok(total_distance(data, P1_SCALE).into())
+    }
+
+    fn part2(&self, data: &Vec<Coordinates>) -> Result<Answer> {
+        Ok(total_distance(data, P2_SCALE).into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Cosmic Expansion"
+    }
+
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
@@ -0,0 +1,143 @@
+// https://adventofcode.com/2023/day/12
+use crate::error::Result;
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+use lru::LruCache;
+use rayon::prelude::*;
+use std::num::NonZeroUsize;
+
+#[derive(Debug)]
+pub struct Springs {
+    chars: String,
+    counts: Vec<usize>,
+}
+
+impl Springs {
+    fn new(chars: String, counts: Vec<usize>) -> Springs {
+        Springs { chars, counts }
+    }
+}
+
+pub fn calculate_permutations(springs: &Springs) -> usize {
+    let mut cache = LruCache::new(NonZeroUsize::new(100).unwrap());
+    permute(
+        springs,
+        springs.chars.len(),
+        springs.counts.len(),
+        &mut cache,
+    )
+}
+
+pub fn permute(
+    springs: &Springs,
+    si: usize,
+    ci: usize,
+    cache: &mut LruCache<(usize, usize), usize>,
+) -> usize {
+    if let Some(&cached_result) = cache.get(&(si, ci)) {
+        return cached_result;
+    }
+
+    let mut result;
+
+    if si == 0 && ci == 0 {
+        result = 1;
+    } else if si == 0 {
+        result = 0;
+    } else if ci == 0 {
+        result = springs.chars[..si].chars().all(|c| c != '#') as usize;
+    } else if springs.chars.chars().nth(si - 1).unwrap() == '.' {
+        result = permute(springs, si - 1, ci, cache);
+    } else {
+        let curr_num = springs.counts[ci - 1];
+
+        if curr_num > si
+            || springs.chars[si - curr_num..si].chars().any(|c| c == '.')
+            || (si > curr_num && springs.chars.chars().nth(si - curr_num - 1).unwrap() == '#')
+        {
+            result = 0;
+        } else {
+            let new_si = if si > curr_num { si - curr_num - 1 } else { 0 };
+
+            result = permute(springs, new_si, ci - 1, cache);
+        }
+
+        if springs.chars.chars().nth(si - 1).unwrap() == '?' {
+            result += permute(springs, si - 1, ci, cache);
+        }
+    }
+
+    cache.put((si, ci), result);
+
+    result
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Springs>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<Springs>> {
+        // Separate the data into two parts, the row and the conditions.
+        // The two sections are separated by a space, and numbers in the conditions are separated by commas.
+        parsing::lines(data)
+            .map(|line| {
+                let row = line.word(0)?;
+                if let Some((i, c)) = row.char_indices().find(|&(_, c)| !".#?".contains(c)) {
+                    return Err(line.invalid_char(i, c));
+                }
+                let conditions = line
+                    .word(1)?
+                    .split(',')
+                    .map(|s| line.parse::<usize>(s))
+                    .collect::<Result<Vec<usize>>>()?;
+                Ok(Springs::new(row.to_string(), conditions))
+            })
+            .collect()
+    }
+
+    fn part1(&self, data: &Vec<Springs>) -> Result<Answer> {
+        // Part 1: Count the number of permutations for base input.
+        let p1: u64 = data.par_iter().map(calculate_permutations).sum::<usize>() as u64;
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &Vec<Springs>) -> Result<Answer> {
+        // Part 2: We need to make each input 5x the size, both the string and the counts.
+        // Iterate over the data and resize/repeat the strings and counts by 5 for both.
+        let data = data
+            .iter()
+            .map(|s| {
+                let new_s = [s.chars.as_str(); 5].join("?");
+
+                let new_c = s
+                    .counts
+                    .iter()
+                    .cycle()
+                    .take(s.counts.len() * 5)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                Springs::new(new_s, new_c)
+            })
+            .collect::<Vec<Springs>>();
+
+        // Count the number of permutations for the new data.
+        let p2: u64 = data.par_iter().map(calculate_permutations).sum::<usize>() as u64;
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Hot Springs"
+    }
+
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
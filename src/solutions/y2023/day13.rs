@@ -1,6 +1,8 @@
 // https://adventofcode.com/2023/day/13
+use crate::error::{Error, Result};
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
 use rayon::prelude::*;
-use crate::library::utility;
 
 #[derive(Debug)]
 pub struct Grid {
@@ -26,7 +28,7 @@ impl Grid {
         // We can then see if lines match, and if not exactly how much they differ by.
         // If the lines don't differ at all we can use that to get our index needed to calculate the score.
         // If the lines differ at exactly one character, then for part two we have found the character we need to flip and thus the new line of reflection.
-        let calc_score = |lines: &Vec<String>| -> usize {
+        let calc_score = |lines: &[String]| -> usize {
             (1..lines.len())
                 .filter(|&i| {
                     let nm = lines[..i]
@@ -38,7 +40,7 @@ impl Grid {
                         .count();
                     nm == target
                 })
-                .map(|i| i * if lines == &rows { 100 } else { 1 })
+                .map(|i| i * if lines == rows { 100 } else { 1 })
                 .sum()
         };
 
@@ -57,88 +59,79 @@ impl Grid {
             .map(|i| {
                 self.grid[i..]
                     .chunks(self.width)
-                    .map(|chunk| chunk.get(0).unwrap())
+                    .map(|chunk| chunk.first().unwrap())
                     .collect()
             })
             .collect()
     }
 }
 
-pub fn solve(data: &[Grid]) -> (usize, usize) {
-    let p1 = data
-        .par_iter()
-        .map(|g| g.calculate_reflection_score(0))
-        .sum();
-
-    let p2 = data
-        .par_iter()
-        .map(|g| g.calculate_reflection_score(1))
-        .sum();
-
-    (p1, p2)
-}
-
-pub fn parse(data: &[String]) -> Vec<Grid> {
-    let mut grids = Vec::new();
-    let mut group = Vec::new();
-    let mut width = None;
-
-    for line in data {
-        if line.is_empty() {
-            if !group.is_empty() {
-                grids.push(Grid::new(group, width.unwrap()));
-                group = Vec::new();
-                width = None;
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<Grid>> {
+        let mut grids = Vec::new();
+        let mut group = Vec::new();
+        let mut width = None;
+
+        for line in parsing::lines(data) {
+            if line.text.is_empty() {
+                if let Some(width) = width.take() {
+                    grids.push(Grid::new(std::mem::take(&mut group), width));
+                }
+            } else {
+                if let Some((i, c)) = line
+                    .text
+                    .char_indices()
+                    .find(|&(_, c)| c != '.' && c != '#')
+                {
+                    return Err(line.invalid_char(i, c));
+                }
+                let width = *width.get_or_insert(line.text.len());
+                if line.text.len() != width {
+                    let message = format!("expected {width} columns, found {}", line.text.len());
+                    return Err(Error::parse(line.number, 1, message));
+                }
+                group.extend(line.text.chars());
             }
-        } else {
-            width.get_or_insert(line.len());
-            group.extend(line.chars());
         }
+
+        if let Some(width) = width {
+            grids.push(Grid::new(group, width));
+        }
+
+        Ok(grids)
     }
 
-    if !group.is_empty() {
-        grids.push(Grid::new(group, width.unwrap()));
+    fn part1(&self, data: &Vec<Grid>) -> Result<Answer> {
+        let p1: usize = data
+            .par_iter()
+            .map(|g| g.calculate_reflection_score(0))
+            .sum();
+
+        Ok(p1.into())
     }
 
-    grids
-}
+    fn part2(&self, data: &Vec<Grid>) -> Result<Answer> {
+        let p2: usize = data
+            .par_iter()
+            .map(|g| g.calculate_reflection_score(1))
+            .sum();
 
-#[allow(dead_code)]
-pub fn run() {
-    let res = solve(&parse(&utility::files::read_file("data/day13.txt")));
-    println!("Day 13:\nStar 1: {}\nStar 2: {}\n", res.0, res.1);
-}
+        Ok(p2.into())
+    }
 
-#[allow(dead_code)]
-pub fn benchmark(c: &mut criterion::Criterion) {
-    let data = parse(&utility::files::read_file("data/day13.txt"));
-    c.bench_function("Day 13 - solve:", |b| b.iter(|| solve(&data)));
-    c.bench_function("Day 13 - parse & solve:", |b| {
-        b.iter(|| {
-            let data = parse(&utility::files::read_file("data/day13.txt"));
-            solve(&data)
-        })
-    });
-}
+    fn name(&self) -> &'static str {
+        "Point of Incidence"
+    }
 
-#[cfg(test)]
-mod tests {
-    #[allow(unused_imports)]
-    use super::*;
-
-    #[test]
-    fn part1() {
-        let expected = 405;
-        let res = solve(&parse(&utility::files::read_file("testdata/day13.txt")));
-        assert_eq!(res.0, expected);
-        println!("Part 1: Expected: {}, Actual: {}", expected, res.0);
+    fn day(&self) -> u32 {
+        13
     }
 
-    #[test]
-    fn part2() {
-        let expected = 400;
-        let res = solve(&parse(&utility::files::read_file("testdata/day13.txt")));
-        assert_eq!(res.1, expected);
-        println!("Part 2: Expected: {}, Actual: {}", expected, res.1);
+    fn year(&self) -> u32 {
+        2023
     }
 }
@@ -0,0 +1,131 @@
+// https://adventofcode.com/2023/day/14
+use crate::error::Result;
+use crate::library::cancel;
+use crate::library::containers::grid::Grid;
+use crate::runner::{answer::Answer, solution::Solution};
+use std::collections::{hash_map::Entry, HashMap};
+
+const TOTAL_CYCLES: i32 = 1_000_000_000;
+
+// This function will tilt the grid to the left.
+// Thereby letting all the rocks that can move, move to the left.
+fn tilt_left(grid: &mut Grid) {
+    // Go thru each row, and for every 'O' move it as far left as it can go.
+    // It only stops when it hits a '#', the edge of the grid or another 'O'.
+    let mut rows = grid.rows();
+    for row in rows.iter_mut() {
+        for i in 0..row.len() {
+            if row[i] == 'O' {
+                for j in (0..i).rev() {
+                    if row[j] == '#' || row[j] == 'O' {
+                        break;
+                    } else {
+                        row.swap(j, j + 1);
+                    }
+                }
+            }
+        }
+    }
+    // Put the rows back into the grid.
+    // Need to go from Vec<Vec<char>> to Vec<String>.
+    *grid = Grid::from_rows(
+        rows.iter().map(|row| row.iter().collect()).collect(),
+        grid.width(),
+    );
+}
+
+fn tilt_right(grid: &mut Grid) {
+    grid.flip_y();
+    tilt_left(grid);
+    grid.flip_y();
+}
+
+fn tilt_up(grid: &mut Grid) {
+    grid.transpose();
+    tilt_left(grid);
+    grid.transpose();
+}
+
+fn tilt_down(grid: &mut Grid) {
+    grid.transpose();
+    tilt_right(grid);
+    grid.transpose();
+}
+
+fn cycle(grid: &mut Grid) {
+    // Tilt the grid in all directions.
+    tilt_up(grid);
+    tilt_left(grid);
+    tilt_down(grid);
+    tilt_right(grid);
+}
+
+fn load(grid: &Grid) -> i32 {
+    grid.columns().iter().fold(0, |acc, s| {
+        acc + s.iter().rev().enumerate().fold(0, |acc, (i, c)| {
+            acc + if c == &'O' { i as i32 + 1 } else { 0 }
+        })
+    })
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid;
+
+    fn parse(&self, data: &[String]) -> Result<Grid> {
+        Grid::parse(data, ".#O")
+    }
+
+    fn part1(&self, data: &Grid) -> Result<Answer> {
+        // Create a copy of the grid for part 1.
+        let mut grid = data.clone();
+        tilt_up(&mut grid);
+        Ok(load(&grid).into())
+    }
+
+    fn part2(&self, data: &Grid) -> Result<Answer> {
+        // Create a copy of the grid for part 2.
+        let mut grid = data.clone();
+
+        // Next section was shamelessly stolen from Lesley Lai.
+        // I used it when I was rewriting my Grid class into it's own thing so a lot of the ideas are the same.
+        let mut table = HashMap::new();
+        let mut i: usize = 0;
+
+        let remaining = loop {
+            // The cycle can take a while to show up, so give up when the day has run out of time.
+            cancel::check()?;
+            match table.entry(grid.clone()) {
+                Entry::Vacant(v) => {
+                    v.insert(i);
+                }
+                Entry::Occupied(ref o) => {
+                    let repetition_iterations = i - o.get();
+                    break (TOTAL_CYCLES - i as i32) % repetition_iterations as i32;
+                }
+            }
+
+            cycle(&mut grid);
+            i += 1;
+        };
+
+        for _ in 0..remaining {
+            cycle(&mut grid);
+        }
+
+        Ok(load(&grid).into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Parabolic Reflector Dish"
+    }
+
+    fn day(&self) -> u32 {
+        14
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
@@ -0,0 +1,115 @@
+// https://adventofcode.com/2023/day/15
+use crate::error::{Error, Result};
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+
+// Custom hashfunction for a string.
+pub fn hash(s: &str) -> i32 {
+    s.chars().fold(0, |acc, c| (acc + c as i32) * 17 % 256)
+}
+
+// Split a step into its label, operation and focal length, or None if it is not a valid step.
+pub fn split_step(s: &str) -> Option<(String, char, i32)> {
+    let index = s.find(['-', '='])?;
+    let split_char = s[index..].chars().next()?;
+    let (label, value) = (&s[..index], &s[index + 1..]);
+
+    let value = match split_char {
+        '-' if value.is_empty() => -1,
+        '=' => value.parse::<i32>().ok()?,
+        _ => return None,
+    };
+
+    Some((label.to_string(), split_char, value))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<String>> {
+        let line = parsing::lines(data)
+            .next()
+            .ok_or_else(|| Error::parse(1, 1, "expected a line of steps"))?;
+
+        line.text
+            .split(',')
+            .map(|s| match split_step(s) {
+                Some(_) => Ok(s.to_string()),
+                None => Err(line.error(s, format!("invalid step {s:?}"))),
+            })
+            .collect()
+    }
+
+    fn part1(&self, data: &Vec<String>) -> Result<Answer> {
+        // For each string, calculate the hash and sum the result.
+        let p1: i32 = data.iter().map(|d| hash(d)).sum();
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &Vec<String>) -> Result<Answer> {
+        // Create a vector of 256 elements, each containing a VecDeque that holds a given String.
+        let mut buckets: Vec<Vec<(String, i32)>> = vec![vec![]; 256];
+
+        // Iterate thru the data..
+        for d in data {
+            // Split the string into a label, a char and a value.
+            let (label, ch, value) =
+                split_step(d).ok_or_else(|| Error::parse(1, 1, format!("invalid step {d:?}")))?;
+            let index = hash(&label);
+
+            match ch {
+                '-' => {
+                    // From the relevant bucket, we need to remove the pair that has the same label if it exists.
+                    if let Some(pos) = buckets[index as usize]
+                        .iter()
+                        .position(|(l, _)| l == &label)
+                    {
+                        buckets[index as usize].remove(pos);
+                    }
+                }
+
+                '=' => {
+                    // From the relevant bucket, if the label already exits, update the value.
+                    // If the label does not exist, add it to the bucket at the end along with the value.
+                    if let Some(pos) = buckets[index as usize]
+                        .iter()
+                        .position(|(l, _)| l == &label)
+                    {
+                        buckets[index as usize][pos].1 = value;
+                    } else {
+                        buckets[index as usize].push((label, value));
+                    }
+                }
+
+                _ => unreachable!("split_step only returns '-' or '='"),
+            }
+        }
+
+        let p2: i32 = buckets.iter().enumerate().fold(0, |acc, (i, b)| {
+            if b.is_empty() {
+                acc
+            } else {
+                acc + b.iter().enumerate().fold(0, |bucket_acc, (j, element)| {
+                    bucket_acc + element.1 * (i as i32 + 1) * (j as i32 + 1)
+                })
+            }
+        });
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Lens Library"
+    }
+
+    fn day(&self) -> u32 {
+        15
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
@@ -1,19 +1,11 @@
 // https://adventofcode.com/2023/day/16
-use crate::library::{
-    containers::grid::{Grid, Position},
-    utility,
-};
+use crate::error::{Error, Result};
+use crate::library::containers::direction::Direction;
+use crate::library::containers::grid::{Grid, Position};
+use crate::runner::{answer::Answer, solution::Solution};
 use rayon::prelude::*;
 use std::collections::HashSet;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct Beam {
     pub dir: Direction,
@@ -25,32 +17,9 @@ impl Beam {
         Self { dir, pos }
     }
 
-    // Get the next position of the beam, make sure to check if it's out of bounds.
+    // Get the next position of the beam, None when it leaves the grid.
     pub fn next(&self, grid: &Grid) -> Option<Position> {
-        let new_position = match self.dir {
-            Direction::Up => {
-                if self.pos.y > 0 {
-                    Position::new(self.pos.x, self.pos.y - 1)
-                } else {
-                    return None;
-                }
-            }
-            Direction::Down => Position::new(self.pos.x, self.pos.y + 1),
-            Direction::Left => {
-                if self.pos.x > 0 {
-                    Position::new(self.pos.x - 1, self.pos.y)
-                } else {
-                    return None;
-                }
-            }
-            Direction::Right => Position::new(self.pos.x + 1, self.pos.y),
-        };
-
-        if grid.is_in_bounds(new_position) {
-            Some(new_position)
-        } else {
-            None
-        }
+        grid.step(self.pos, self.dir)
     }
 }
 
@@ -60,21 +29,16 @@ pub fn raytrace(grid: &Grid, starting_beam: &Beam) -> i32 {
     let mut beams: Vec<Beam> = Vec::new();
 
     // Get the first character in the grid, and based off what it is, we create a beam going in the correct direction.
-    beams.push(starting_beam.clone());
+    beams.push(*starting_beam);
 
     // Create a HashSet to store the beams we've already visited, and their points, since the path will be identical.
     let mut visited: HashSet<Beam> = HashSet::new();
 
-    // Keep going until the Vec is empty.
-    while !beams.is_empty() {
-        // Get the next beam from the Vec.
-        let beam = beams.pop().unwrap();
-
+    // Keep going until the Vec is empty, getting the next beam from the Vec.
+    while let Some(beam) = beams.pop() {
         // If we've already had a beam with this path and position, we can skip it.
-        if visited.contains(&beam) {
+        if !visited.insert(beam) {
             continue;
-        } else {
-            visited.insert(beam);
         }
 
         // Need to get the next character in the grid according to the beam's position, if we can.
@@ -144,7 +108,7 @@ pub fn raytrace(grid: &Grid, starting_beam: &Beam) -> i32 {
                     // If the next character is None, we've hit the end of the grid, so we can skip this beam.
                     continue;
                 }
-                _ => todo!("This will never be reached, but it's here to make the compiler happy."),
+                Some(c) => unreachable!("Grid was parsed with only known tiles, found {c:?}"),
             }
         }
     }
@@ -157,93 +121,73 @@ pub fn raytrace(grid: &Grid, starting_beam: &Beam) -> i32 {
         .len() as i32
 }
 
-pub fn solve(data: &Grid) -> (i32, i32) {
-    // Get the first character in the grid, and based off what it is, we create a beam going in the correct direction.
-    let ch = data.get_pos(Position::new(0, 0)).unwrap();
+pub struct Day16;
 
-    let beam = match ch {
-        '|' | '\\' => Beam::new(Direction::Down, Position::new(0, 0)),
-        '-' | '.' => Beam::new(Direction::Right, Position::new(0, 0)),
-        '/' => Beam::new(Direction::Up, Position::new(0, 0)),
-        _ => todo!("This will never be reached, but it's here to make the compiler happy."),
-    };
+impl Solution for Day16 {
+    type Input = Grid;
 
-    let p1 = raytrace(data, &beam);
+    fn parse(&self, data: &[String]) -> Result<Grid> {
+        Grid::parse(data, "./\\|-")
+    }
 
-    // For part 2 we need to find the maximum amount of tiles that can be reached by a beam, so we need to try all possible starting directions and positions from the edges of the grid.
-    // So iterate thru all points on west edge, and go right, then iterate thru all points on the north edge, and go down, etc.
+    fn part1(&self, data: &Grid) -> Result<Answer> {
+        // Get the first character in the grid, and based off what it is, we create a beam going in the correct direction.
+        let ch = data
+            .get_pos(Position::new(0, 0))
+            .ok_or_else(|| Error::unsolvable("the grid is empty"))?;
 
-    // Create a vector to hold all the beams we want to raytrace.
-    let mut beams: Vec<Beam> = Vec::new();
+        let beam = match ch {
+            '|' | '\\' => Beam::new(Direction::Down, Position::new(0, 0)),
+            '/' => Beam::new(Direction::Up, Position::new(0, 0)),
+            _ => Beam::new(Direction::Right, Position::new(0, 0)),
+        };
 
-    // Iterating through the top and bottom rows
-    for x in 0..data.width() {
-        beams.push(Beam::new(Direction::Down, Position::new(x, 0)));
-        beams.push(Beam::new(
-            Direction::Up,
-            Position::new(x, data.height() - 1),
-        ));
+        Ok(raytrace(data, &beam).into())
     }
 
-    // Iterating through the left and right columns
-    for y in 0..data.height() {
-        beams.push(Beam::new(Direction::Right, Position::new(0, y)));
-        beams.push(Beam::new(
-            Direction::Left,
-            Position::new(data.width() - 1, y),
-        ));
-    }
+    fn part2(&self, data: &Grid) -> Result<Answer> {
+        // For part 2 we need to find the maximum amount of tiles that can be reached by a beam, so we need to try all possible starting directions and positions from the edges of the grid.
+        // So iterate thru all points on west edge, and go right, then iterate thru all points on the north edge, and go down, etc.
 
-    // Iterate through all the beams, and raytrace them, and keep track of the maximum amount of tiles we've seen.
-    let p2 = beams
-        .par_iter()
-        .map(|beam| raytrace(data, beam))
-        .reduce(|| 0, |max, tiles_seen| std::cmp::max(max, tiles_seen));
+        // Create a vector to hold all the beams we want to raytrace.
+        let mut beams: Vec<Beam> = Vec::new();
 
-    (p1, p2)
-}
+        // Iterating through the top and bottom rows
+        for x in 0..data.width() {
+            beams.push(Beam::new(Direction::Down, Position::new(x, 0)));
+            beams.push(Beam::new(
+                Direction::Up,
+                Position::new(x, data.height() - 1),
+            ));
+        }
 
-pub fn parse(data: &[String]) -> Grid {
-    let rows = data.iter().map(|s| s.to_string()).collect();
-    Grid::from_rows(rows, data[0].len())
-}
+        // Iterating through the left and right columns
+        for y in 0..data.height() {
+            beams.push(Beam::new(Direction::Right, Position::new(0, y)));
+            beams.push(Beam::new(
+                Direction::Left,
+                Position::new(data.width() - 1, y),
+            ));
+        }
 
-#[allow(dead_code)]
-pub fn run() {
-    let res = solve(&parse(&utility::files::read_file("data/day16.txt")));
-    println!("Day 16:\nStar 1: {}\nStar 2: {}\n", res.0, res.1);
-}
+        // Iterate through all the beams, and raytrace them, and keep track of the maximum amount of tiles we've seen.
+        let p2: i32 = beams
+            .par_iter()
+            .map(|beam| raytrace(data, beam))
+            .reduce(|| 0, std::cmp::max);
 
-#[allow(dead_code)]
-pub fn benchmark(c: &mut criterion::Criterion) {
-    let data = parse(&utility::files::read_file("data/day16.txt"));
-    c.bench_function("Day 16 - solve:", |b| b.iter(|| solve(&data)));
-    c.bench_function("Day 16 - parse & solve:", |b| {
-        b.iter(|| {
-            let data = parse(&utility::files::read_file("data/day16.txt"));
-            solve(&data)
-        })
-    });
-}
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "The Floor Will Be Lava"
+    }
 
-#[cfg(test)]
-mod tests {
-    #[allow(unused_imports)]
-    use super::*;
-
-    #[test]
-    fn part1() {
-        let expected = 46;
-        let res = solve(&parse(&utility::files::read_file("testdata/day16.txt")));
-        assert_eq!(res.0, expected);
-        println!("Part 1: Expected: {}, Actual: {}", expected, res.0);
+    fn day(&self) -> u32 {
+        16
     }
 
-    #[test]
-    fn part2() {
-        let expected = 51;
-        let res = solve(&parse(&utility::files::read_file("testdata/day16.txt")));
-        assert_eq!(res.1, expected);
-        println!("Part 2: Expected: {}, Actual: {}", expected, res.1);
+    fn year(&self) -> u32 {
+        2023
     }
 }
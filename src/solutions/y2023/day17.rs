@@ -0,0 +1,84 @@
+// https://adventofcode.com/2023/day/17
+use crate::error::{Error, Result};
+use crate::library::containers::direction::Direction;
+use crate::library::containers::grid::{Grid, Position};
+use crate::library::search::{self, Search};
+use crate::runner::{answer::Answer, solution::Solution};
+
+// The crucible can only go straight for between min and max blocks, then it has to turn left or right.
+// It starts in the top-left as if it came in going down or right, so it can set off either way.
+// The search keeps the route it took, so it can be drawn in debug mode.
+fn route(data: &Grid<u8>, min: usize, max: usize) -> Result<Search<(Position, Direction), u32>> {
+    let start = Position::new(0, 0);
+    let goal = Position::new(data.width() - 1, data.height() - 1);
+
+    search::dijkstra(
+        [(start, Direction::Down), (start, Direction::Right)],
+        |&(pos, dir): &(Position, Direction)| {
+            [dir.turn_left(), dir.turn_right()]
+                .into_iter()
+                .flat_map(move |turn| {
+                    search::straight(data, pos, turn, min..=max, |&loss| u32::from(loss))
+                        .map(move |(next, loss)| ((next, turn), loss))
+                })
+        },
+        |&(pos, _)| pos == goal,
+    )
+}
+
+// Returns None when the bottom-right can not be reached at all.
+fn cheapest(data: &Grid<u8>, min: usize, max: usize) -> Result<Option<u32>> {
+    Ok(route(data, min, max)?.cost())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<u8>;
+
+    fn parse(&self, data: &[String]) -> Result<Grid<u8>> {
+        Grid::from_digits(data)
+    }
+
+    fn part1(&self, data: &Grid<u8>) -> Result<Answer> {
+        let p1 = cheapest(data, 1, 3)?
+            .ok_or_else(|| Error::unsolvable("the crucible can not reach the factory"))?;
+
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &Grid<u8>) -> Result<Answer> {
+        let p2 = cheapest(data, 4, 10)?
+            .ok_or_else(|| Error::unsolvable("the ultra crucible can not reach the factory"))?;
+
+        Ok(p2.into())
+    }
+
+    // Draw the route of both crucibles onto the map, with the arrows they entered every block with.
+    fn debug(&self, data: &Grid<u8>) -> Result<Option<String>> {
+        let map = data.map(|&d| char::from_digit(u32::from(d), 10).unwrap_or('?'));
+        let mut drawings = Vec::new();
+        for (part, min, max) in [(1, 1, 3), (2, 4, 10)] {
+            let search = route(data, min, max)?;
+            let path: Vec<Position> = search.path.iter().map(|&(pos, _)| pos).collect();
+            let loss = search.cost().map_or("none".to_string(), |c| c.to_string());
+            drawings.push(format!(
+                "Part {part}, heat loss {loss}:\n{}",
+                map.draw_path(&path).to_string(true)
+            ));
+        }
+        Ok(Some(drawings.join("\n\n")))
+    }
+
+    fn name(&self) -> &'static str {
+        "Clumsy Crucible"
+    }
+
+    fn day(&self) -> u32 {
+        17
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
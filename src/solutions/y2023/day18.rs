@@ -0,0 +1,116 @@
+// https://adventofcode.com/2023/day/18
+use crate::error::Result;
+use crate::library::containers::direction::Direction;
+use crate::library::containers::point::Point;
+use crate::library::utility::parsing;
+use crate::runner::{answer::Answer, solution::Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    direction: Direction,
+    steps: u32,
+    colour: u32,
+}
+
+// Calculate interior for a given set of instructions.
+pub fn calculate(data: &[Instruction]) -> i128 {
+    let mut vertices: Vec<Point<i128>> = Vec::new();
+    let mut current = Point::default();
+    let mut border: i128 = 0;
+
+    // Move along the path, adding vertices and updating the border size.
+    for instruction in data {
+        current = current + instruction.direction.delta().cast() * instruction.steps as i128;
+        border += instruction.steps as i128;
+        vertices.push(current);
+    }
+
+    // Shoelace formula.
+    let mut area = 0;
+    for i in 0..vertices.len() {
+        let j = (i + 1) % vertices.len();
+        area += vertices[i].x * vertices[j].y;
+        area -= vertices[j].x * vertices[i].y;
+    }
+
+    // Pick's theorem.
+    (area - border + 2) / 2 + border
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<Instruction>> {
+        // Each line is a single instruction of format: 'char, u32, (#u32)' where the last part is a hex colour. Read into a vector of instructions.
+        let mut instructions = Vec::new();
+        for line in parsing::lines(data) {
+            let letter = line.word(0)?;
+            let direction = match letter {
+                "U" | "D" | "L" | "R" => letter.chars().next().and_then(Direction::from_char),
+                _ => None,
+            }
+            .ok_or_else(|| line.error(letter, format!("invalid direction {letter:?}")))?;
+            let steps = line.parse::<u32>(line.word(1)?)?;
+
+            // The colour doubles as the real instruction, its last digit has to be a direction from 0 to 3.
+            let colour = line.word(2)?;
+            let hex = colour
+                .strip_prefix("(#")
+                .and_then(|c| c.strip_suffix(')'))
+                .ok_or_else(|| line.error(colour, "expected a colour like (#70c710)"))?;
+            let colour = u32::from_str_radix(hex, 16)
+                .ok()
+                .filter(|c| c % 16 < 4)
+                .ok_or_else(|| line.error(hex, format!("invalid colour {hex:?}")))?;
+
+            instructions.push(Instruction {
+                direction,
+                steps,
+                colour,
+            });
+        }
+        Ok(instructions)
+    }
+
+    fn part1(&self, data: &Vec<Instruction>) -> Result<Answer> {
+        Ok(calculate(data).into())
+    }
+
+    fn part2(&self, data: &Vec<Instruction>) -> Result<Answer> {
+        // Go thru the instructions, and update the direction and steps for each instruction.
+        // The new direction is the colour % 16, and the new steps is the colour / 16.
+        let mut instructions = Vec::new();
+
+        for instruction in data {
+            let direction = match instruction.colour % 16 {
+                0 => Direction::Right,
+                1 => Direction::Down,
+                2 => Direction::Left,
+                3 => Direction::Up,
+                _ => unreachable!(),
+            };
+            let steps = instruction.colour / 16;
+            instructions.push(Instruction {
+                direction,
+                steps,
+                colour: instruction.colour,
+            });
+        }
+
+        Ok(calculate(&instructions).into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Lavaduct Lagoon"
+    }
+
+    fn day(&self) -> u32 {
+        18
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
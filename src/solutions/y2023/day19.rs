@@ -1,5 +1,7 @@
 // https://adventofcode.com/2023/day/19
-use crate::library::utility;
+use crate::error::{Error, Result};
+use crate::library::utility::parsing::{self, Line};
+use crate::runner::{answer::Answer, solution::Solution};
 use std::collections::{HashMap, VecDeque};
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
@@ -12,26 +14,32 @@ pub struct Part {
 
 // Reads a string and converts it into the respective values for each category and makes the part.
 impl Part {
-    fn from_string(input: &str) -> Self {
+    fn from_string(line: &Line) -> Result<Self> {
         let mut part = Part {
             x: 0,
             m: 0,
             a: 0,
             s: 0,
         };
-        for pair in input[1..input.len() - 1].split(',') {
-            let mut kv = pair.split('=');
-            let key = kv.next().unwrap();
-            let value = kv.next().unwrap().parse::<i128>().unwrap();
+        let input = line
+            .text
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| line.error(line.text, "expected a part like {x=1,m=2,a=3,s=4}"))?;
+        for pair in input.split(',') {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| line.error(pair, "expected a rating like x=1"))?;
+            let value = line.parse::<i128>(value)?;
             match key {
                 "x" => part.x = value,
                 "m" => part.m = value,
                 "a" => part.a = value,
                 "s" => part.s = value,
-                _ => (),
+                _ => return Err(line.error(key, format!("unknown category {key:?}"))),
             }
         }
-        part
+        Ok(part)
     }
 }
 
@@ -52,27 +60,27 @@ pub struct Rule {
 }
 
 impl Rule {
-    fn from_string(input: &str) -> Self {
-        if input.contains(':') {
-            let parts: Vec<&str> = input.split(|c| c == '<' || c == '>').collect();
-            let category = parts[0].chars().next();
-            let comparator = input.chars().nth(parts[0].len());
-            let value_target: Vec<&str> = parts[1].split(':').collect();
-            let value = value_target[0].parse::<i128>().unwrap();
-            let target = value_target[1].to_string();
-            Rule {
-                category,
-                comparator,
-                value: Some(value),
-                target,
+    fn from_string(line: &Line, input: &str) -> Result<Self> {
+        if let Some((condition, target)) = input.split_once(':') {
+            let (category, value) = condition
+                .split_once(['<', '>'])
+                .ok_or_else(|| line.error(condition, "expected a condition like a<2006"))?;
+            if !["x", "m", "a", "s"].contains(&category) {
+                return Err(line.error(category, format!("unknown category {category:?}")));
             }
+            Ok(Rule {
+                category: category.chars().next(),
+                comparator: condition[category.len()..].chars().next(),
+                value: Some(line.parse::<i128>(value)?),
+                target: target.to_string(),
+            })
         } else {
-            Rule {
+            Ok(Rule {
                 category: None,
                 comparator: None,
                 value: None,
                 target: input.to_string(),
-            }
+            })
         }
     }
 }
@@ -84,40 +92,49 @@ pub struct Workflow {
 }
 
 impl Workflow {
-    fn from_string(input: &str) -> Self {
-        let parts: Vec<&str> = input.split('{').collect();
-        let label = parts[0].to_string();
-        let rules_str = &parts[1][..parts[1].len() - 1]; // Remove the closing '}'
-        let rules: Vec<Rule> = rules_str.split(',').map(|s| Rule::from_string(s)).collect();
-        Workflow { label, rules }
+    fn from_string(line: &Line) -> Result<Self> {
+        let (label, rules_str) = line.split_once("{")?;
+        let rules_str = rules_str
+            .strip_suffix('}')
+            .ok_or_else(|| line.error(rules_str, "expected a closing '}'"))?;
+        let rules = rules_str
+            .split(',')
+            .map(|rule| Rule::from_string(line, rule))
+            .collect::<Result<Vec<Rule>>>()?;
+
+        // Every workflow has to end in a rule without a condition, parts can not fall off the end.
+        match rules.last() {
+            Some(rule) if rule.category.is_none() => Ok(Workflow {
+                label: label.to_string(),
+                rules,
+            }),
+            _ => Err(line.error(rules_str, "expected a final rule without a condition")),
+        }
     }
 }
 
 // This function will recursively go thru the intervals and use the workflows to calculate them and return a value which eventually is summed up.
-pub fn rangeflow(
-    workflows: &HashMap<String, Workflow>,
-    name: &String,
-    intervals: PartRange,
-) -> i128 {
+pub fn rangeflow(workflows: &HashMap<String, Workflow>, name: &str, intervals: PartRange) -> i128 {
     // First we need to check if the name is an "A" or an "R".
     if name == "A" {
         // If we did get accepted then all these intervals are valid and we need to reduce on them as needed.
         return [intervals.x, intervals.m, intervals.a, intervals.s]
             .iter()
             .map(|&(lo, hi)| hi - lo + 1)
-            .fold(1, |acc, val| acc * val);
+            .product();
     } else if name == "R" {
         return 0;
     }
 
     // Get a mutable copy of the current interval so we can modify it and pass it on.
-    let mut intervals = intervals.clone();
+    let mut intervals = intervals;
+    let rules = &workflows[name].rules;
 
     // Stores the overval calculated result of everything.
     let mut res: i128 = 0;
 
     // Iterate over the rules, skipping the final one as we only care about the ones that have conditions.
-    for rule in &workflows[name].rules[..1] {
+    for rule in &rules[..rules.len() - 1] {
         // Get the low and high values for specifically the interval we are working on.
         let (low, high) = match rule.category.unwrap() {
             'x' => intervals.x,
@@ -163,7 +180,8 @@ pub fn rangeflow(
         }
     }
 
-    res + rangeflow(workflows, name, intervals)
+    // Whatever is left over falls through to the target of the final rule.
+    res + rangeflow(workflows, &rules[rules.len() - 1].target, intervals)
 }
 
 // This function will follow the flow of a part and return a bool for acceptance or rejection based on it.
@@ -173,18 +191,17 @@ pub fn flow(workflows: &HashMap<String, Workflow>, part: &Part) -> bool {
     // First one in is always the "in".
     queue.push_back("in".to_string());
     // Keep going until queue is empty or we return early..?
-    while !queue.is_empty() {
-        let label = queue.pop_front();
-        let rules = &workflows[&label.unwrap()].rules;
+    while let Some(label) = queue.pop_front() {
+        let rules = &workflows[&label].rules;
 
         // Based on the rules we can process it one of two ways, either understand the rule and then move to target.. or if the rule is simply a redirect, then return or add to queue.
         // Iterate thru the rules:
         for rule in rules {
             // If the rule has a category of None, it's a simple redirect or a return, else if there is a value we can process according to the value.
-            if rule.category.is_some() {
+            if let Some(category) = rule.category {
                 // If something exits.. we need to operate on the rule fully and follow thru, either with a return, or an addition to queue and break or simply do nothing and go to next rule.
                 // The first section gets the category from the rule, and inserts the value from the part instead into variable.
-                let category: i128 = match rule.category.unwrap() {
+                let category: i128 = match category {
                     'x' => part.x,
                     'm' => part.m,
                     'a' => part.a,
@@ -237,91 +254,95 @@ pub fn flow(workflows: &HashMap<String, Workflow>, part: &Part) -> bool {
     unreachable!("This function should always return well before this point!");
 }
 
-pub fn solve(data: &(HashMap<String, Workflow>, Vec<Part>)) -> (i128, i128) {
-    let (workflows, parts) = data;
+pub struct Day19;
 
-    let p1: i128 = parts
-        .iter()
-        .map(|p| {
-            if flow(&workflows, &p) {
-                p.x + p.m + p.a + p.s
-            } else {
-                0
-            }
-        })
-        .sum();
-
-    let p2: i128 = rangeflow(
-        workflows,
-        &"in".to_string(),
-        PartRange {
-            x: (1, 4000),
-            m: (1, 4000),
-            s: (1, 4000),
-            a: (1, 4000),
-        },
-    );
-
-    (p1, p2)
-}
+impl Solution for Day19 {
+    type Input = (HashMap<String, Workflow>, Vec<Part>);
 
-pub fn parse(data: &[String]) -> (HashMap<String, Workflow>, Vec<Part>) {
-    let mut workflows: HashMap<String, Workflow> = HashMap::new();
-    let mut parts: Vec<Part> = Vec::new();
+    fn parse(&self, data: &[String]) -> Result<(HashMap<String, Workflow>, Vec<Part>)> {
+        let mut workflows: HashMap<String, Workflow> = HashMap::new();
+        let mut parts: Vec<Part> = Vec::new();
 
-    let split = data.iter().position(|x| x == "").unwrap();
-    let (s1, s2) = data.split_at(split);
+        let lines: Vec<_> = parsing::lines(data).collect();
+        let split = lines
+            .iter()
+            .position(|x| x.text.is_empty())
+            .ok_or_else(|| {
+                Error::parse(data.len() + 1, 1, "expected a blank line before the parts")
+            })?;
+        let (s1, s2) = lines.split_at(split);
+
+        // Iterate over the first part and assign workflows.
+        for workflow in s1.iter() {
+            let w = Workflow::from_string(workflow)?;
+            workflows.insert(w.label.to_string(), w);
+        }
 
-    // Iterate over the first part and assign workflows.
-    for workflow in s1.to_vec().iter() {
-        let w = Workflow::from_string(workflow);
-        workflows.insert(w.label.to_string(), w);
-    }
+        // Every rule has to lead somewhere, and everything starts at "in".
+        for line in s1.iter() {
+            let (_, rules) = line.split_once("{")?;
+            for rule in rules.trim_end_matches('}').split(',') {
+                let target = rule.rsplit_once(':').map_or(rule, |(_, target)| target);
+                if target != "A" && target != "R" && !workflows.contains_key(target) {
+                    return Err(line.error(target, format!("unknown workflow {target:?}")));
+                }
+            }
+        }
+        if !workflows.contains_key("in") {
+            return Err(Error::parse(1, 1, "expected a workflow named \"in\""));
+        }
 
-    // Iterate over the second part and assign parts.
-    for part in s2[1..].to_vec().iter() {
-        parts.push(Part::from_string(part));
+        // Iterate over the second part and assign parts.
+        for part in s2[1..].iter() {
+            parts.push(Part::from_string(part)?);
+        }
+
+        Ok((workflows, parts))
     }
 
-    (workflows, parts)
-}
+    fn part1(&self, data: &(HashMap<String, Workflow>, Vec<Part>)) -> Result<Answer> {
+        let (workflows, parts) = data;
 
-#[allow(dead_code)]
-pub fn run() {
-    let res = solve(&parse(&utility::files::read_file("data/day19.txt")));
-    println!("Day 19:\nStar 1: {}\nStar 2: {}\n", res.0, res.1);
-}
+        let p1: i128 = parts
+            .iter()
+            .map(|p| {
+                if flow(workflows, p) {
+                    p.x + p.m + p.a + p.s
+                } else {
+                    0
+                }
+            })
+            .sum();
 
-#[allow(dead_code)]
-pub fn benchmark(c: &mut criterion::Criterion) {
-    let data = parse(&utility::files::read_file("data/day19.txt"));
-    c.bench_function("Day 19 - solve:", |b| b.iter(|| solve(&data)));
-    c.bench_function("Day 19 - parse & solve:", |b| {
-        b.iter(|| {
-            let data = parse(&utility::files::read_file("data/day19.txt"));
-            solve(&data)
-        })
-    });
-}
+        Ok(p1.into())
+    }
+
+    fn part2(&self, data: &(HashMap<String, Workflow>, Vec<Part>)) -> Result<Answer> {
+        let (workflows, _) = data;
+
+        let p2: i128 = rangeflow(
+            workflows,
+            "in",
+            PartRange {
+                x: (1, 4000),
+                m: (1, 4000),
+                s: (1, 4000),
+                a: (1, 4000),
+            },
+        );
+
+        Ok(p2.into())
+    }
+
+    fn name(&self) -> &'static str {
+        "Aplenty"
+    }
 
-#[cfg(test)]
-mod tests {
-    #[allow(unused_imports)]
-    use super::*;
-
-    #[test]
-    fn part1() {
-        let expected = 19114;
-        let res = solve(&parse(&utility::files::read_file("testdata/day19.txt")));
-        assert_eq!(res.0, expected);
-        println!("Part 1: Expected: {}, Actual: {}", expected, res.0);
+    fn day(&self) -> u32 {
+        19
     }
 
-    #[test]
-    fn part2() {
-        let expected = 167409079868000;
-        let res = solve(&parse(&utility::files::read_file("testdata/day19.txt")));
-        assert_eq!(res.1, expected);
-        println!("Part 2: Expected: {}, Actual: {}", expected, res.1);
+    fn year(&self) -> u32 {
+        2023
     }
 }
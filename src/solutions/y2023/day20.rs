@@ -0,0 +1,33 @@
+// https://adventofcode.com/2023/day/20
+use crate::error::Result;
+use crate::runner::{answer::Answer, solution::Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<String>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<String>> {
+        Ok(data.to_vec())
+    }
+
+    fn part1(&self, _data: &Vec<String>) -> Result<Answer> {
+        Ok(Answer::Unimplemented)
+    }
+
+    fn part2(&self, _data: &Vec<String>) -> Result<Answer> {
+        Ok(Answer::Unimplemented)
+    }
+
+    fn name(&self) -> &'static str {
+        "Pulse Propagation"
+    }
+
+    fn day(&self) -> u32 {
+        20
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
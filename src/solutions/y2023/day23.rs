@@ -0,0 +1,33 @@
+// https://adventofcode.com/2023/day/23
+use crate::error::Result;
+use crate::runner::{answer::Answer, solution::Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<String>;
+
+    fn parse(&self, data: &[String]) -> Result<Vec<String>> {
+        Ok(data.to_vec())
+    }
+
+    fn part1(&self, _data: &Vec<String>) -> Result<Answer> {
+        Ok(Answer::Unimplemented)
+    }
+
+    fn part2(&self, _data: &Vec<String>) -> Result<Answer> {
+        Ok(Answer::Unimplemented)
+    }
+
+    fn name(&self) -> &'static str {
+        "A Long Walk"
+    }
+
+    fn day(&self) -> u32 {
+        23
+    }
+
+    fn year(&self) -> u32 {
+        2023
+    }
+}
@@ -1,43 +1,33 @@
-// https://adventofcode.com/2023/day/XX
+// https://adventofcode.com/YEAR/day/XX
+use crate::error::Result;
+use crate::runner::{answer::Answer, solution::Solution};
 
-pub fn solve(data: &[String]) -> (i32, i32) {
-    (0, 0)
-}
+pub struct DayXX;
 
-pub fn parse(data: &[String]) -> Vec<String> {
-    data.iter().map(|s| s.to_string()).collect()
-}
+impl Solution for DayXX {
+    type Input = Vec<String>;
 
-#[allow(dead_code)]
-pub fn run() {
-    let res = solve(&parse(&crate::library::read_file("data/dayXX.txt")));
-    println!("Day XX:\nStar 1: {}\nStar 2: {}\n", res.0, res.1);
-}
+    fn parse(&self, data: &[String]) -> Result<Vec<String>> {
+        Ok(data.to_vec())
+    }
 
-#[allow(dead_code)]
-pub fn benchmark(c: &mut criterion::Criterion) {
-    let data = parse(&crate::library::read_file("data/dayXX.txt"));
-    c.bench_function("Day XX", |b| b.iter(|| solve(&data)));
-}
+    fn part1(&self, _data: &Vec<String>) -> Result<Answer> {
+        Ok(Answer::Unimplemented)
+    }
+
+    fn part2(&self, _data: &Vec<String>) -> Result<Answer> {
+        Ok(Answer::Unimplemented)
+    }
+
+    fn name(&self) -> &'static str {
+        ""
+    }
 
-#[cfg(test)]
-mod tests {
-    #[allow(unused_imports)]
-    use super::*;
-
-    #[test]
-    fn part1() {
-        let expected = 0;
-        let res = solve(&parse(&crate::library::read_file("testdata/dayXX.txt")));
-        assert_eq!(res.0, expected);
-        println!("Part 1: Expected: {}, Actual: {}", expected, res.0);
+    fn day(&self) -> u32 {
+        DAY
     }
 
-    #[test]
-    fn part2() {
-        let expected = 0;
-        let res = solve(&parse(&crate::library::read_file("testdata/dayXX.txt")));
-        assert_eq!(res.1, expected);
-        println!("Part 2: Expected: {}, Actual: {}", expected, res.1);
+    fn year(&self) -> u32 {
+        YEAR
     }
 }